@@ -0,0 +1,73 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use Bulletproof dependencies
+use bulletproofs::{BulletproofGens, PedersenGens};
+// Use the MiMC hash crate and code
+use nizkp_benchmark::hash::mimc::{self, bulletproof};
+
+const AGGREGATION_SIZES: [usize; 4] = [1, 2, 4, 8];
+
+// Benchmarks amortized aggregated Bulletproofs verification of K independent
+// MiMC hash statements in a single R1CS proof.
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    // Define a source of randomness
+    let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+
+    // Generate the MiMC round constants
+    let constants = (0..mimc::MIMC_ROUNDS)
+        .map(|_| curve25519_dalek_ng::scalar::Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    // Define the generators for the Pedersen commitments
+    let pc_gens = PedersenGens::default();
+
+    let mut group = c.benchmark_group("bulletproof_aggregate");
+
+    for &k in AGGREGATION_SIZES.iter() {
+        let bp_gens = BulletproofGens::new(k * (mimc::MIMC_ROUNDS + 1) * 2, 1);
+
+        group.bench_function(format!("proof/{}", k), |b| {
+            // Per-sample (note that a sample can be many iterations) setup goes here
+            b.iter(|| {
+                // Measured code goes here
+                black_box(bulletproof::aggregate::prove_aggregated(
+                    k,
+                    mimc::MIMC_ROUNDS,
+                    &constants,
+                    &pc_gens,
+                    &bp_gens,
+                    &mut rng,
+                ))
+            });
+        });
+
+        let (proof, commitments, images) = bulletproof::aggregate::prove_aggregated(
+            k,
+            mimc::MIMC_ROUNDS,
+            &constants,
+            &pc_gens,
+            &bp_gens,
+            &mut rng,
+        );
+
+        group.bench_function(format!("verification/{}", k), |b| {
+            // Per-sample (note that a sample can be many iterations) setup goes here
+            b.iter(|| {
+                // Measured code goes here
+                let verification_result = bulletproof::aggregate::verify_aggregated(
+                    &proof,
+                    &commitments,
+                    &images,
+                    mimc::MIMC_ROUNDS,
+                    &constants,
+                    &pc_gens,
+                    &bp_gens,
+                );
+                black_box(assert!(verification_result.is_ok()));
+            });
+        });
+    }
+}
@@ -6,7 +6,7 @@ use rand::{rngs::StdRng, SeedableRng};
 use bulletproofs::{BulletproofGens, PedersenGens};
 use merlin::Transcript;
 // Use the MiMC hash crate and code
-use nizkp_benchmark::hash::mimc::{self, bulletproof};
+use nizkp_benchmark::hash::mimc::{self, bulletproof, security};
 
 const GENS_CAPACITY: usize = (mimc::MIMC_ROUNDS + 1) * 2;
 
@@ -31,7 +31,7 @@ pub fn benchmark(c: &mut Criterion) {
     let xr = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
 
     // Compute the MiMC hash image
-    let image = bulletproof::mimc(&xl, &xr, mimc::MIMC_ROUNDS, &constants);
+    let image = bulletproof::mimc(&xl, &xr, mimc::MIMC_ROUNDS, &constants, 3);
 
     // Create the proof including commitments
     let (proof, commitments) = {
@@ -57,7 +57,8 @@ pub fn benchmark(c: &mut Criterion) {
             right_alloc_scalar,
             mimc::MIMC_ROUNDS,
             &constants,
-            &image
+            &image,
+            3
         )
         .is_ok());
 
@@ -75,9 +76,11 @@ pub fn benchmark(c: &mut Criterion) {
     // Get metrics from the proof
     let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
     let serilized_proof_size_bytes = proof.serialized_size();
+    let (conjectured_security_bits, proven_security_bits) =
+        security::bulletproof_security_bits(mimc::MIMC_ROUNDS * 2);
     println!(
         "Bulletproof proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} \n\tSecurity level (bits): {} conjectured, {} proven",
-        runtime_proof_size_bytes, serilized_proof_size_bytes, "?", "?"
+        runtime_proof_size_bytes, serilized_proof_size_bytes, conjectured_security_bits, proven_security_bits
     );
 
     let mut group = c.benchmark_group("bulletproof");
@@ -108,7 +111,8 @@ pub fn benchmark(c: &mut Criterion) {
                 right_alloc_scalar,
                 mimc::MIMC_ROUNDS,
                 &constants,
-                &image
+                &image,
+                3
             )
             .is_ok());
 
@@ -140,7 +144,8 @@ pub fn benchmark(c: &mut Criterion) {
                 right_alloc_scalar,
                 mimc::MIMC_ROUNDS,
                 &constants,
-                &image
+                &image,
+                3
             )
             .is_ok());
 
@@ -0,0 +1,95 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use Bulletproof dependencies
+use bulletproofs::{BulletproofGens, PedersenGens};
+use curve25519_dalek_ng::scalar::Scalar;
+use merlin::Transcript;
+// Use the MiMC hash crate and code
+use nizkp_benchmark::hash::mimc::{self, bulletproof};
+
+const BIT_WIDTH: usize = 32;
+const GENS_CAPACITY: usize = (BIT_WIDTH + 1) * 2;
+
+// Benchmarks the Bulletproof bit-decomposition range-proof gadget
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+
+    let secret_value: u64 = (1u64 << (BIT_WIDTH - 1)) + 1;
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(GENS_CAPACITY, 1);
+
+    // Create the proof including the commitment
+    let (proof, commitment) = {
+        let mut prover_transcript = Transcript::new(b"RangeProofGadget");
+        let mut prover = bulletproofs::r1cs::Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (commitment, var) =
+            prover.commit(Scalar::from(secret_value), Scalar::random(&mut rng));
+        let quantity = bulletproof::AllocatedQuantity {
+            variable: var,
+            assignment: Some(secret_value),
+        };
+
+        assert!(bulletproof::range::range_proof_gadget(&mut prover, quantity, BIT_WIDTH).is_ok());
+
+        (prover.prove(&bp_gens).unwrap(), commitment)
+    };
+
+    // Get metrics from the proof
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes = proof.serialized_size();
+    println!(
+        "Range proof gadget metrics ({} bits): \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {}",
+        BIT_WIDTH, runtime_proof_size_bytes, serilized_proof_size_bytes
+    );
+
+    let mut group = c.benchmark_group("bulletproof_range_gadget");
+
+    group.bench_function("proof", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let mut prover_transcript = Transcript::new(b"RangeProofGadget");
+            let mut prover = bulletproofs::r1cs::Prover::new(&pc_gens, &mut prover_transcript);
+
+            let (commitment, var) =
+                prover.commit(Scalar::from(secret_value), Scalar::random(&mut rng));
+            let quantity = bulletproof::AllocatedQuantity {
+                variable: var,
+                assignment: Some(secret_value),
+            };
+
+            assert!(
+                bulletproof::range::range_proof_gadget(&mut prover, quantity, BIT_WIDTH).is_ok()
+            );
+
+            black_box((prover.prove(&bp_gens).unwrap(), commitment))
+        });
+    });
+
+    group.bench_function("verification", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let mut verifier_transcript = Transcript::new(b"RangeProofGadget");
+            let mut verifier = bulletproofs::r1cs::Verifier::new(&mut verifier_transcript);
+
+            let var = verifier.commit(commitment);
+            let quantity = bulletproof::AllocatedQuantity {
+                variable: var,
+                assignment: None,
+            };
+
+            assert!(
+                bulletproof::range::range_proof_gadget(&mut verifier, quantity, BIT_WIDTH)
+                    .is_ok()
+            );
+
+            black_box(assert!(verifier.verify(&proof, &pc_gens, &bp_gens).is_ok()))
+        });
+    });
+}
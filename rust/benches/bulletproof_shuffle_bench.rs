@@ -0,0 +1,97 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, SeedableRng};
+// Use Bulletproof dependencies
+use bulletproofs::r1cs::Variable;
+use bulletproofs::{BulletproofGens, PedersenGens};
+use curve25519_dalek_ng::scalar::Scalar;
+use merlin::Transcript;
+// Use the MiMC hash crate and code
+use nizkp_benchmark::hash::mimc::{self, bulletproof};
+
+const SHUFFLE_SIZE: usize = 16;
+const GENS_CAPACITY: usize = 2 * (SHUFFLE_SIZE - 1) * 2;
+
+// Benchmarks the Bulletproof randomized-constraint shuffle gadget
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+
+    let x: Vec<Scalar> = (0..SHUFFLE_SIZE).map(|_| Scalar::random(&mut rng)).collect();
+    let mut y = x.clone();
+    y.shuffle(&mut rng);
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(GENS_CAPACITY, 1);
+
+    // Create the proof including commitments
+    let (proof, x_commitments, y_commitments) = {
+        let mut prover_transcript = Transcript::new(b"Shuffle");
+        let mut prover = bulletproofs::r1cs::Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (x_commitments, x_vars): (Vec<_>, Vec<_>) = x
+            .iter()
+            .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+            .unzip();
+        let (y_commitments, y_vars): (Vec<_>, Vec<_>) = y
+            .iter()
+            .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+            .unzip();
+
+        assert!(bulletproof::shuffle::shuffle_gadget(&mut prover, x_vars, y_vars).is_ok());
+
+        (prover.prove(&bp_gens).unwrap(), x_commitments, y_commitments)
+    };
+
+    // Get metrics from the proof
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes = proof.serialized_size();
+    println!(
+        "Shuffle proof metrics ({} elements): \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {}",
+        SHUFFLE_SIZE, runtime_proof_size_bytes, serilized_proof_size_bytes
+    );
+
+    let mut group = c.benchmark_group("bulletproof_shuffle");
+
+    group.bench_function("proof", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let mut prover_transcript = Transcript::new(b"Shuffle");
+            let mut prover = bulletproofs::r1cs::Prover::new(&pc_gens, &mut prover_transcript);
+
+            let (x_commitments, x_vars): (Vec<_>, Vec<_>) = x
+                .iter()
+                .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+                .unzip();
+            let (y_commitments, y_vars): (Vec<_>, Vec<_>) = y
+                .iter()
+                .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+                .unzip();
+
+            assert!(bulletproof::shuffle::shuffle_gadget(&mut prover, x_vars, y_vars).is_ok());
+
+            black_box((prover.prove(&bp_gens).unwrap(), x_commitments, y_commitments))
+        });
+    });
+
+    group.bench_function("verification", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let mut verifier_transcript = Transcript::new(b"Shuffle");
+            let mut verifier = bulletproofs::r1cs::Verifier::new(&mut verifier_transcript);
+
+            let x_vars: Vec<Variable> =
+                x_commitments.iter().map(|c| verifier.commit(*c)).collect();
+            let y_vars: Vec<Variable> =
+                y_commitments.iter().map(|c| verifier.commit(*c)).collect();
+
+            assert!(bulletproof::shuffle::shuffle_gadget(&mut verifier, x_vars, y_vars).is_ok());
+
+            black_box(assert!(verifier.verify(&proof, &pc_gens, &bp_gens).is_ok()))
+        });
+    });
+}
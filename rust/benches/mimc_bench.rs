@@ -3,13 +3,19 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // Use standard library dependencies
 use std::time::Duration;
 
+mod bulletproof_aggregate_mimc_bench;
 mod bulletproof_mimc_bench;
+mod bulletproof_range_gadget_bench;
+mod bulletproof_shuffle_bench;
+mod persist_bench;
+mod snark_batch_mimc_bench;
 mod snark_mimc_bench;
+mod spartan_mimc_bench;
 mod stark_mimc_bench;
 
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(100).measurement_time(Duration::from_secs(20));
-    targets = bulletproof_mimc_bench::benchmark, snark_mimc_bench::benchmark, stark_mimc_bench::benchmark
+    targets = bulletproof_mimc_bench::benchmark, bulletproof_aggregate_mimc_bench::benchmark, bulletproof_shuffle_bench::benchmark, bulletproof_range_gadget_bench::benchmark, snark_mimc_bench::benchmark, snark_batch_mimc_bench::benchmark, spartan_mimc_bench::benchmark, stark_mimc_bench::benchmark, persist_bench::benchmark
 }
 criterion_main!(benches);
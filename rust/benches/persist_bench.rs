@@ -0,0 +1,140 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+// Use the MiMC hash crate and code
+use bls12_381::Bls12;
+use bulletproofs::{r1cs::Prover, BulletproofGens, PedersenGens};
+use nizkp_benchmark::bench_config::BenchConfig;
+use nizkp_benchmark::hash::mimc::{self, bulletproof, persist, snark, stark};
+use winterfell::{crypto::hashers::Blake3_256, math::fields::f128::BaseElement, ProofOptions};
+
+// Benchmarks cold-start deserialization of each backend's persisted proof
+// artifact, a cost the in-memory benchmarks in the other bench files never
+// pay (they keep the freshly-created proof in memory rather than round
+// tripping it through disk).
+pub fn benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("persist");
+
+    // Groth16.
+    {
+        let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+        let mimc_rounds = snark::MIMC_ROUNDS_BLS12_381;
+        let constants = (0..mimc_rounds)
+            .map(|_| bls12_381::Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let crs = {
+            let circuit = snark::MiMCCircuit {
+                xl: None,
+                xr: None,
+                constants: &constants,
+            };
+            bellman::groth16::generate_random_parameters::<Bls12, _, _>(circuit, &mut rng)
+                .unwrap()
+        };
+        let xl = bls12_381::Scalar::random(&mut rng);
+        let xr = bls12_381::Scalar::random(&mut rng);
+        let proof = {
+            let circuit = snark::MiMCCircuit {
+                xl: Some(xl),
+                xr: Some(xr),
+                constants: &constants,
+            };
+            bellman::groth16::create_random_proof(circuit, &crs, &mut rng).unwrap()
+        };
+
+        let path = std::env::temp_dir().join("nizkp-benchmark-bench-groth16-proof.bin");
+        persist::write_groth16_proof(&path, &proof).unwrap();
+
+        group.bench_function("deserialize_groth16_proof", |b| {
+            b.iter(|| black_box(persist::read_groth16_proof(&path).unwrap()));
+        });
+    }
+
+    // STARK.
+    {
+        let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+        let config = BenchConfig::new(mimc::MIMC_ROUNDS, mimc::SAMPLES);
+        let options = ProofOptions::new(
+            config.num_queries,
+            config.blowup_factor,
+            config.grinding_factor,
+            config.field_extension,
+            config.fri_folding_factor,
+            config.fri_remainder_max_degree,
+        );
+        type Hasher = Blake3_256<BaseElement>;
+
+        let round_constants = (0..config.rounds)
+            .map(|_| BaseElement::new(rng.next_u64() as u128))
+            .collect::<Vec<_>>();
+        let xl = BaseElement::new(rng.next_u64() as u128);
+        let xr = BaseElement::new(rng.next_u64() as u128);
+
+        let prover = stark::MiMCProver::<Hasher>::new(options);
+        let trace = prover.build_trace(xl, xr, &round_constants);
+        let proof = prover.prove(trace).unwrap();
+
+        let path = std::env::temp_dir().join("nizkp-benchmark-bench-stark-proof.bin");
+        persist::write_stark_proof(&path, &proof).unwrap();
+
+        group.bench_function("deserialize_stark_proof", |b| {
+            b.iter(|| black_box(persist::read_stark_proof(&path).unwrap()));
+        });
+    }
+
+    // Bulletproof.
+    {
+        let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+        let mimc_rounds = mimc::MIMC_ROUNDS;
+        let constants = (0..mimc_rounds)
+            .map(|_| curve25519_dalek_ng::scalar::Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let pc_gens = PedersenGens::default();
+        // `bulletproof::gens_capacity` is private, so this mirrors its
+        // formula directly (two multipliers per round, plus one spare,
+        // doubled).
+        let bp_gens = BulletproofGens::new((mimc_rounds * 2 + 1) * 2, 1);
+
+        let xl = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+        let xr = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+        let image = bulletproof::mimc(&xl, &xr, mimc_rounds, &constants, 3);
+
+        let proof = {
+            let mut prover_transcript = merlin::Transcript::new(b"MiMC");
+            let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+            let (_, var_l) = prover.commit(xl, curve25519_dalek_ng::scalar::Scalar::random(&mut rng));
+            let (_, var_r) = prover.commit(xr, curve25519_dalek_ng::scalar::Scalar::random(&mut rng));
+            let left_alloc_scalar = bulletproof::AllocatedScalar {
+                variable: var_l,
+                assignment: Some(xl),
+            };
+            let right_alloc_scalar = bulletproof::AllocatedScalar {
+                variable: var_r,
+                assignment: Some(xr),
+            };
+
+            assert!(bulletproof::mimc_gadget(
+                &mut prover,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                mimc_rounds,
+                &constants,
+                &image,
+                3,
+            )
+            .is_ok());
+
+            prover.prove(&bp_gens).unwrap()
+        };
+
+        let path = std::env::temp_dir().join("nizkp-benchmark-bench-bulletproof.bin");
+        persist::write_bulletproof(&path, &proof).unwrap();
+
+        group.bench_function("deserialize_bulletproof", |b| {
+            b.iter(|| black_box(persist::read_bulletproof(&path).unwrap()));
+        });
+    }
+}
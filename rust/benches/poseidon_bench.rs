@@ -0,0 +1,15 @@
+// Use Criterion dependency for benchmarking
+use criterion::{criterion_group, criterion_main, Criterion};
+// Use standard library dependencies
+use std::time::Duration;
+
+mod poseidon_bulletproof_bench;
+mod poseidon_snark_bench;
+mod poseidon_stark_bench;
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(100).measurement_time(Duration::from_secs(20));
+    targets = poseidon_snark_bench::benchmark, poseidon_bulletproof_bench::benchmark, poseidon_stark_bench::benchmark
+}
+criterion_main!(benches);
@@ -0,0 +1,161 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use Bulletproof dependencies
+use bulletproofs::{BulletproofGens, PedersenGens};
+use merlin::Transcript;
+// Use the Poseidon hash crate and code
+use nizkp_benchmark::hash::poseidon;
+
+const GENS_CAPACITY: usize = (poseidon::FULL_ROUNDS * poseidon::T + poseidon::PARTIAL_ROUNDS) * 3 * 2;
+
+// Benchmarks the Bulletproof Poseidon hash
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    // Define a source of randomness
+    let mut rng: StdRng = SeedableRng::from_seed(poseidon::RANDOMNESS_SEED);
+
+    // Generate the Poseidon round constants
+    let round_constants = (0..(poseidon::FULL_ROUNDS + poseidon::PARTIAL_ROUNDS))
+        .map(|_| {
+            let mut rc = [curve25519_dalek_ng::scalar::Scalar::zero(); poseidon::T];
+            for slot in rc.iter_mut() {
+                *slot = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+            }
+            rc
+        })
+        .collect::<Vec<_>>();
+
+    // Generate the MDS matrix as a Cauchy matrix, mirroring
+    // `poseidon::bulletproof`'s private `generate_mds` (not callable from
+    // here directly, see that module's doc comment for why it's MDS).
+    let mds = {
+        let mut xs = [curve25519_dalek_ng::scalar::Scalar::zero(); poseidon::T];
+        let mut ys = [curve25519_dalek_ng::scalar::Scalar::zero(); poseidon::T];
+        for x in xs.iter_mut() {
+            *x = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+        }
+        for y in ys.iter_mut() {
+            *y = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+        }
+
+        let mut mds = [[curve25519_dalek_ng::scalar::Scalar::zero(); poseidon::T]; poseidon::T];
+        for i in 0..poseidon::T {
+            for j in 0..poseidon::T {
+                mds[i][j] = (xs[i] + ys[j]).invert();
+            }
+        }
+        mds
+    };
+
+    // Define the generators for the Pedersen commitments
+    let pc_gens = PedersenGens::default();
+    // Define the generators for the Bulletproofs
+    let bp_gens = BulletproofGens::new(GENS_CAPACITY, 1);
+
+    // Generate a random preimage
+    let xl = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+    let xr = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+
+    // Compute the Poseidon hash image
+    let image = poseidon::bulletproof::poseidon(&xl, &xr, &round_constants, &mds);
+
+    let mut group = c.benchmark_group("poseidon_bulletproof");
+
+    group.bench_function("proof", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let mut prover_transcript = Transcript::new(b"Poseidon");
+            let mut prover = bulletproofs::r1cs::Prover::new(&pc_gens, &mut prover_transcript);
+
+            let (com_l, var_l) =
+                prover.commit(xl, curve25519_dalek_ng::scalar::Scalar::random(&mut rng));
+            let (com_r, var_r) =
+                prover.commit(xr, curve25519_dalek_ng::scalar::Scalar::random(&mut rng));
+            let left_alloc_scalar = nizkp_benchmark::hash::mimc::bulletproof::AllocatedScalar {
+                variable: var_l,
+                assignment: Some(xl),
+            };
+            let right_alloc_scalar = nizkp_benchmark::hash::mimc::bulletproof::AllocatedScalar {
+                variable: var_r,
+                assignment: Some(xr),
+            };
+
+            assert!(poseidon::bulletproof::poseidon_gadget(
+                &mut prover,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                &round_constants,
+                &mds,
+                &image
+            )
+            .is_ok());
+
+            black_box((prover.prove(&bp_gens).unwrap(), (com_l, com_r)))
+        });
+    });
+
+    let (proof, commitments) = {
+        let mut prover_transcript = Transcript::new(b"Poseidon");
+        let mut prover = bulletproofs::r1cs::Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (com_l, var_l) =
+            prover.commit(xl, curve25519_dalek_ng::scalar::Scalar::random(&mut rng));
+        let (com_r, var_r) =
+            prover.commit(xr, curve25519_dalek_ng::scalar::Scalar::random(&mut rng));
+        let left_alloc_scalar = nizkp_benchmark::hash::mimc::bulletproof::AllocatedScalar {
+            variable: var_l,
+            assignment: Some(xl),
+        };
+        let right_alloc_scalar = nizkp_benchmark::hash::mimc::bulletproof::AllocatedScalar {
+            variable: var_r,
+            assignment: Some(xr),
+        };
+
+        assert!(poseidon::bulletproof::poseidon_gadget(
+            &mut prover,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            &round_constants,
+            &mds,
+            &image
+        )
+        .is_ok());
+
+        (prover.prove(&bp_gens).unwrap(), (com_l, com_r))
+    };
+
+    group.bench_function("verification", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let mut verifier_transcript = Transcript::new(b"Poseidon");
+            let mut verifier = bulletproofs::r1cs::Verifier::new(&mut verifier_transcript);
+
+            let var_l = verifier.commit(commitments.0);
+            let var_r = verifier.commit(commitments.1);
+            let left_alloc_scalar = nizkp_benchmark::hash::mimc::bulletproof::AllocatedScalar {
+                variable: var_l,
+                assignment: None,
+            };
+            let right_alloc_scalar = nizkp_benchmark::hash::mimc::bulletproof::AllocatedScalar {
+                variable: var_r,
+                assignment: None,
+            };
+
+            assert!(poseidon::bulletproof::poseidon_gadget(
+                &mut verifier,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                &round_constants,
+                &mds,
+                &image
+            )
+            .is_ok());
+
+            black_box(assert!(verifier.verify(&proof, &pc_gens, &bp_gens).is_ok()))
+        });
+    });
+}
@@ -0,0 +1,140 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use zkSNARK dependencies
+use bellman::groth16::{
+    create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+};
+use bls12_381::{Bls12, Scalar};
+use ff::Field;
+// Use the Poseidon hash crate and code
+use nizkp_benchmark::hash::mimc::security;
+use nizkp_benchmark::hash::poseidon::{self, snark};
+
+// Benchmarks the zkSNARK Poseidon hash
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    // Define a source of randomness
+    let mut rng: StdRng = SeedableRng::from_seed(poseidon::RANDOMNESS_SEED);
+
+    // Generate the Poseidon round constants
+    let round_constants = (0..(poseidon::FULL_ROUNDS + poseidon::PARTIAL_ROUNDS))
+        .map(|_| {
+            let mut rc = [Scalar::zero(); poseidon::T];
+            for slot in rc.iter_mut() {
+                *slot = Scalar::random(&mut rng);
+            }
+            rc
+        })
+        .collect::<Vec<_>>();
+
+    // Generate the Poseidon MDS matrix as a Cauchy matrix
+    let mds = {
+        let mut xs = [Scalar::zero(); poseidon::T];
+        let mut ys = [Scalar::zero(); poseidon::T];
+        for x in xs.iter_mut() {
+            *x = Scalar::random(&mut rng);
+        }
+        for y in ys.iter_mut() {
+            *y = Scalar::random(&mut rng);
+        }
+
+        let mut mds = [[Scalar::zero(); poseidon::T]; poseidon::T];
+        for i in 0..poseidon::T {
+            for j in 0..poseidon::T {
+                mds[i][j] = (xs[i] + ys[j]).invert().unwrap();
+            }
+        }
+        mds
+    };
+
+    // Generate the Common Reference String (CRS)
+    let crs = {
+        let circuit = snark::PoseidonCircuit {
+            xl: None,
+            xr: None,
+            round_constants: &round_constants,
+            mds: &mds,
+        };
+
+        generate_random_parameters::<Bls12, _, _>(circuit, &mut rng).unwrap()
+    };
+
+    // Prepare the verification key (for proof verification)
+    let pvk = prepare_verifying_key(&crs.vk);
+
+    // Generate a random preimage
+    let xl = bls12_381::Scalar::random(&mut rng);
+    let xr = bls12_381::Scalar::random(&mut rng);
+
+    // Compute the Poseidon hash image
+    let image = snark::poseidon(xl, xr, &round_constants, &mds);
+
+    // Create a groth16 proof with the defined parameters
+    let proof = {
+        let circuit = snark::PoseidonCircuit {
+            xl: Some(xl),
+            xr: Some(xr),
+            round_constants: &round_constants,
+            mds: &mds,
+        };
+
+        create_random_proof(circuit, &crs, &mut rng).unwrap()
+    };
+
+    // Get metrics from the proof
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes_compressed = proof.a.to_compressed().len()
+        + proof.b.to_compressed().len()
+        + proof.c.to_compressed().len();
+    let (conjectured_security_bits, proven_security_bits) =
+        security::groth16_bls12_381_security_bits();
+    println!(
+        "Poseidon SNARK proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} compressed \n\tSecurity level (bits): {} conjectured, {} proven",
+        runtime_proof_size_bytes, serilized_proof_size_bytes_compressed, conjectured_security_bits, proven_security_bits
+    );
+
+    let mut group = c.benchmark_group("poseidon_snark");
+
+    // Benchmark setup time
+    group.bench_function("setup", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let circuit = snark::PoseidonCircuit {
+                xl: None,
+                xr: None,
+                round_constants: &round_constants,
+                mds: &mds,
+            };
+            let crs = generate_random_parameters::<Bls12, _, _>(circuit, &mut rng).unwrap();
+            black_box(prepare_verifying_key(&crs.vk))
+        });
+    });
+
+    // Benchmark proof time
+    group.bench_function("proof", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let circuit = snark::PoseidonCircuit {
+                xl: Some(xl),
+                xr: Some(xr),
+                round_constants: &round_constants,
+                mds: &mds,
+            };
+            black_box(create_random_proof(circuit, &crs, &mut rng))
+        });
+    });
+
+    // Benchmark verification time
+    group.bench_function("verification", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            let verification_result = verify_proof(&pvk, &proof, &[image]);
+            black_box(assert!(verification_result.is_ok()));
+        });
+    });
+}
@@ -0,0 +1,14 @@
+// Use Criterion dependency for benchmarking
+use criterion::{criterion_group, criterion_main, Criterion};
+// Use standard library dependencies
+use std::time::Duration;
+
+mod range_bulletproof_bench;
+mod range_mpc_bulletproof_bench;
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(100).measurement_time(Duration::from_secs(20));
+    targets = range_bulletproof_bench::benchmark, range_mpc_bulletproof_bench::benchmark
+}
+criterion_main!(benches);
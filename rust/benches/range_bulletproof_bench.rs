@@ -0,0 +1,71 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use Bulletproof dependencies
+use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
+use merlin::Transcript;
+// Use the range proof crate and code
+use nizkp_benchmark::range;
+
+const BIT_WIDTHS: [usize; 4] = [8, 16, 32, 64];
+
+// Benchmarks Bulletproof range proofs for a selection of bit-widths
+pub fn benchmark(c: &mut Criterion) {
+    let mut rng: StdRng = SeedableRng::from_seed(range::RANDOMNESS_SEED);
+
+    let pc_gens = PedersenGens::default();
+
+    let mut group = c.benchmark_group("range_bulletproof");
+
+    for n in BIT_WIDTHS {
+        let bp_gens = BulletproofGens::new(n, 1);
+        let secret_value: u64 = if n == 64 {
+            u64::MAX / 2
+        } else {
+            (1u64 << (n - 1)) + 1
+        };
+        let blinding = curve25519_dalek_ng::scalar::Scalar::random(&mut rng);
+
+        let (proof, commitment) = {
+            let mut prover_transcript = Transcript::new(b"RangeProof");
+            RangeProof::prove_single(
+                &bp_gens,
+                &pc_gens,
+                &mut prover_transcript,
+                secret_value,
+                &blinding,
+                n,
+            )
+            .unwrap()
+        };
+
+        group.bench_function(format!("proof/{}", n), |b| {
+            b.iter(|| {
+                let mut prover_transcript = Transcript::new(b"RangeProof");
+                black_box(
+                    RangeProof::prove_single(
+                        &bp_gens,
+                        &pc_gens,
+                        &mut prover_transcript,
+                        secret_value,
+                        &blinding,
+                        n,
+                    )
+                    .unwrap(),
+                )
+            });
+        });
+
+        group.bench_function(format!("verification/{}", n), |b| {
+            b.iter(|| {
+                let mut verifier_transcript = Transcript::new(b"RangeProof");
+                black_box(
+                    proof
+                        .verify_single(&bp_gens, &pc_gens, &mut verifier_transcript, &commitment, n)
+                        .is_ok(),
+                )
+            });
+        });
+    }
+}
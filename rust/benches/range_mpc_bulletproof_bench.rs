@@ -0,0 +1,43 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+// Use Bulletproof dependencies
+use bulletproofs::{BulletproofGens, PedersenGens};
+use curve25519_dalek_ng::scalar::Scalar;
+// Use the range proof crate and code
+use nizkp_benchmark::range::{self, bulletproof::mpc};
+
+const AGGREGATION_SIZES: [usize; 5] = [1, 2, 4, 8, 16];
+
+// Benchmarks aggregated Bulletproof range proofs as the aggregation size m grows
+pub fn benchmark(c: &mut Criterion) {
+    let n = 32;
+    let mut rng: StdRng = SeedableRng::from_seed(range::RANDOMNESS_SEED);
+
+    let mut group = c.benchmark_group("range_bulletproof_mpc");
+
+    for m in AGGREGATION_SIZES {
+        let bp_gens = BulletproofGens::new(n, m);
+        let pc_gens = PedersenGens::default();
+
+        let values: Vec<u64> = (0..m).map(|_| rng.next_u32() as u64).collect();
+        let blindings: Vec<Scalar> = (0..m).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_function(format!("proof/{}", m), |b| {
+            b.iter(|| {
+                black_box(mpc::prove_aggregated(&bp_gens, &pc_gens, n, &values, &blindings))
+            });
+        });
+
+        let (proof, commitments) = mpc::prove_aggregated(&bp_gens, &pc_gens, n, &values, &blindings);
+
+        group.bench_function(format!("verification/{}", m), |b| {
+            b.iter(|| {
+                black_box(
+                    mpc::verify_aggregated(&bp_gens, &pc_gens, &proof, &commitments, n).is_ok(),
+                )
+            });
+        });
+    }
+}
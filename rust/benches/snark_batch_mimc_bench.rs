@@ -0,0 +1,52 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use zkSNARK dependencies
+use bellman::groth16::{generate_random_parameters, prepare_verifying_key};
+use bls12_381::Bls12;
+// Use the MiMC hash crate and code
+use nizkp_benchmark::hash::mimc::{self, snark};
+
+const BATCH_SIZES: [usize; 3] = [1, 10, 100];
+
+// Benchmarks amortized batched Groth16 verification of the zkSNARK MiMC hash
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    // Define a source of randomness
+    let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+
+    // Generate the MiMC round constants
+    let constants = (0..snark::MIMC_ROUNDS_BLS12_381)
+        .map(|_| bls12_381::Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    // Generate the Common Reference String (CRS)
+    let crs = {
+        let circuit = snark::MiMCCircuit {
+            xl: None,
+            xr: None,
+            constants: &constants,
+        };
+
+        generate_random_parameters::<Bls12, _, _>(circuit, &mut rng).unwrap()
+    };
+
+    // Prepare the verification key (for proof verification)
+    let pvk = prepare_verifying_key(&crs.vk);
+
+    let mut group = c.benchmark_group("snark_batch");
+
+    for &n in BATCH_SIZES.iter() {
+        let proofs = snark::batch::create_random_proofs_batch(n, &crs, &constants, &mut rng);
+
+        group.bench_function(format!("verification/{}", n), |b| {
+            // Per-sample (note that a sample can be many iterations) setup goes here
+            b.iter(|| {
+                // Measured code goes here
+                let verification_result = snark::batch::verify_proofs_batch(&pvk, &proofs, &mut rng);
+                black_box(assert!(matches!(verification_result, Ok(true))));
+            });
+        });
+    }
+}
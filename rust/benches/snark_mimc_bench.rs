@@ -9,7 +9,7 @@ use bellman::groth16::{
 use bls12_381::Bls12;
 use ff::Field;
 // Use the MiMC hash crate and code
-use nizkp_benchmark::hash::mimc::{self, snark};
+use nizkp_benchmark::hash::mimc::{self, security, snark};
 
 // Benchmarks the zkSNARK MiMC hash
 pub fn benchmark(c: &mut Criterion) {
@@ -18,7 +18,7 @@ pub fn benchmark(c: &mut Criterion) {
     let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
 
     // Generate the MiMC round constants
-    let constants = (0..mimc::MIMC_ROUNDS)
+    let constants = (0..snark::MIMC_ROUNDS_BLS12_381)
         .map(|_| bls12_381::Scalar::random(&mut rng))
         .collect::<Vec<_>>();
 
@@ -105,9 +105,11 @@ pub fn benchmark(c: &mut Criterion) {
         + proof.b.to_compressed().len()
         + proof.c.to_compressed().len();
     // NOTE: uncompressed size is twice the compressed size.
+    let (conjectured_security_bits, proven_security_bits) =
+        security::groth16_bls12_381_security_bits();
     println!(
         "SNARK proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} compressed \n\tSecurity level (bits): {} conjectured, {} proven",
-        runtime_proof_size_bytes, serilized_proof_size_bytes_compressed, "?", "?"
+        runtime_proof_size_bytes, serilized_proof_size_bytes_compressed, conjectured_security_bits, proven_security_bits
     );
 
     let mut group = c.benchmark_group("snark");
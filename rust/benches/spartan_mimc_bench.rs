@@ -0,0 +1,47 @@
+// Use Criterion dependency for benchmarking
+use criterion::{black_box, Criterion};
+// Use standard library dependencies
+use rand::{rngs::StdRng, SeedableRng};
+// Use Bulletproof dependencies (reused for the transparent witness commitment)
+use bulletproofs::BulletproofGens;
+use curve25519_dalek_ng::scalar::Scalar;
+// Use the MiMC hash crate and code
+use nizkp_benchmark::hash::mimc::{self, spartan};
+
+// Benchmarks the transparent (Spartan-style) NIZK MiMC hash
+pub fn benchmark(c: &mut Criterion) {
+    // One-time setup code goes here
+    let mut rng: StdRng = SeedableRng::from_seed(mimc::RANDOMNESS_SEED);
+
+    let constants = (0..mimc::MIMC_ROUNDS)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let r1cs = spartan::mimc_r1cs(mimc::MIMC_ROUNDS, &constants);
+
+    let xl = Scalar::random(&mut rng);
+    let xr = Scalar::random(&mut rng);
+    let z = spartan::mimc_witness(mimc::MIMC_ROUNDS, xl, xr, &constants);
+
+    let gens = BulletproofGens::new(r1cs.num_vars.max(64), 1);
+
+    let mut group = c.benchmark_group("spartan");
+
+    group.bench_function("proof", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            black_box(spartan::prove_mimc(&r1cs, &z, &gens, &mut rng))
+        });
+    });
+
+    let (proof, commitment) = spartan::prove_mimc(&r1cs, &z, &gens, &mut rng);
+
+    group.bench_function("verification", |b| {
+        // Per-sample (note that a sample can be many iterations) setup goes here
+        b.iter(|| {
+            // Measured code goes here
+            black_box(spartan::verify_mimc(&r1cs, &proof, &commitment, &gens))
+        });
+    });
+}
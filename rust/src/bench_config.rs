@@ -0,0 +1,67 @@
+use winterfell::FieldExtension;
+
+/// Parameters that can be swept across backends without recompiling: the
+/// round count and sample count apply everywhere, while the FRI/query
+/// fields only affect the Winterfell (STARK) backend and are ignored by
+/// backends that don't have an analogous notion (e.g. Bulletproofs).
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    pub rounds: usize,
+    pub samples: u32,
+    pub field_extension: FieldExtension,
+    pub num_queries: usize,
+    pub blowup_factor: usize,
+    pub grinding_factor: u32,
+    pub fri_folding_factor: usize,
+    pub fri_remainder_max_degree: usize,
+}
+
+impl BenchConfig {
+    /// Builds a config with the crate's long-standing default Winterfell
+    /// proof options (see the former `hash::mimc::stark` constants), varying
+    /// only the round and sample counts.
+    pub fn new(rounds: usize, samples: u32) -> Self {
+        BenchConfig {
+            rounds,
+            samples,
+            field_extension: FieldExtension::None,
+            num_queries: 42,
+            blowup_factor: 8,
+            grinding_factor: 16,
+            fri_folding_factor: 8,
+            fri_remainder_max_degree: 31,
+        }
+    }
+}
+
+/// One backend's result against one [`BenchConfig`]: a single row of a
+/// parameter sweep, as produced by `crate::sweep::run`.
+#[derive(Clone, Debug)]
+pub struct BenchRow {
+    pub backend: String,
+    pub rounds: usize,
+    pub proving_secs: f64,
+    pub verifying_secs: f64,
+    pub proof_size_bytes: usize,
+    pub conjectured_security_bits: u32,
+    pub proven_security_bits: u32,
+}
+
+impl BenchRow {
+    pub fn csv_header() -> &'static str {
+        "backend,rounds,proving_secs,verifying_secs,proof_size_bytes,conjectured_security_bits,proven_security_bits"
+    }
+
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.backend,
+            self.rounds,
+            self.proving_secs,
+            self.verifying_secs,
+            self.proof_size_bytes,
+            self.conjectured_security_bits,
+            self.proven_security_bits
+        )
+    }
+}
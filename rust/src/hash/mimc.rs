@@ -1,5 +1,8 @@
 pub mod bulletproof;
+pub mod persist;
+pub mod security;
 pub mod snark;
+pub mod spartan;
 pub mod stark;
 
 pub const MIMC_ROUNDS: usize = 255; // must be power of two -1, e.g. 7, 15, 31, etc.
@@ -19,4 +22,12 @@ pub fn run() {
     println!("Proving and verifying Bulletproof...");
     bulletproof::run();
     println!("Finished proving and verifying Bulletproof!");
+
+    println!("Proving and verifying transparent (Spartan-style) NIZK...");
+    spartan::run();
+    println!("Finished proving and verifying transparent (Spartan-style) NIZK!");
+
+    println!("Persisting and reloading proof artifacts...");
+    persist::run();
+    println!("Finished persisting and reloading proof artifacts!");
 }
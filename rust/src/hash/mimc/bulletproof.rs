@@ -1,6 +1,11 @@
 // The code in this file is adapted from https://github.com/lovesh/bulletproofs-r1cs-gadgets/blob/master/src/gadget_mimc.rs
 
+pub mod aggregate;
+pub mod range;
+pub mod shuffle;
+
 use super::*;
+use crate::bench_config::{BenchConfig, BenchRow};
 use bulletproofs::{
     r1cs::{ConstraintSystem, LinearCombination, Prover, R1CSError, Variable, Verifier},
     BulletproofGens, PedersenGens,
@@ -10,11 +15,23 @@ use merlin::Transcript;
 use rand::{rngs::StdRng, SeedableRng};
 use std::time::{Duration, Instant};
 
-// GENS_CAPACITY limits the max number of MIMC_ROUNDS rounds possible
-const GENS_CAPACITY: usize = (MIMC_ROUNDS + 1) * 2;
+// Bulletproof generators needed for `mimc_rounds` rounds of the S-box with
+// exponent `d`: `multipliers_per_round(d)` multipliers per round (2 for the
+// crate's default cube, `d = 3`), plus one spare, doubled (which also covers
+// the multiplier count rounded up to the next power of two).
+fn gens_capacity(mimc_rounds: usize, d: u64) -> usize {
+    (mimc_rounds * multipliers_per_round(d) + 1) * 2
+}
 
 pub fn run() {
-    let mimc_rounds = MIMC_ROUNDS;
+    run_with_config(&BenchConfig::new(MIMC_ROUNDS, SAMPLES));
+}
+
+/// Runs `run()`'s single prove/verify cycle against a caller-supplied
+/// [`BenchConfig`] instead of the crate's compile-time `MIMC_ROUNDS`, so the
+/// round count can be explored without recompiling (see `crate::sweep`).
+pub fn run_with_config(config: &BenchConfig) {
+    let mimc_rounds = config.rounds;
 
     // Define a source of randomness
     let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
@@ -27,14 +44,14 @@ pub fn run() {
     // Define the generators for the Pedersen commitments
     let pc_gens = PedersenGens::default();
     // Define the generators for the Bulletproofs
-    let bp_gens = BulletproofGens::new(GENS_CAPACITY, 1);
+    let bp_gens = BulletproofGens::new(gens_capacity(mimc_rounds, 3), 1);
 
     // Generate a random preimage
     let xl = Scalar::random(&mut rng);
     let xr = Scalar::random(&mut rng);
 
     // Compute the MiMC hash image
-    let image = mimc(&xl, &xr, mimc_rounds, &constants);
+    let image = mimc(&xl, &xr, mimc_rounds, &constants, 3);
 
     // Create the proof including commitments
     let (proof, commitments) = {
@@ -58,7 +75,8 @@ pub fn run() {
             right_alloc_scalar,
             mimc_rounds,
             &constants,
-            &image
+            &image,
+            3
         )
         .is_ok());
 
@@ -93,7 +111,8 @@ pub fn run() {
             right_alloc_scalar,
             mimc_rounds,
             &constants,
-            &image
+            &image,
+            3
         )
         .is_ok());
 
@@ -111,9 +130,11 @@ pub fn run() {
     // Get metrics from the proof
     let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
     let serilized_proof_size_bytes = proof.serialized_size();
+    let (conjectured_security_level, proven_security_level) =
+        super::security::bulletproof_security_bits(mimc_rounds * 2);
     println!(
         "Proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} \n\tSecurity level (bits): {} conjectured, {} proven",
-        runtime_proof_size_bytes, serilized_proof_size_bytes, "?", "?"
+        runtime_proof_size_bytes, serilized_proof_size_bytes, conjectured_security_level, proven_security_level
     );
 
     // Get metrics from the commitments
@@ -130,8 +151,112 @@ fn test_run() {
     run();
 }
 
+/// Runs `run()`'s prove/verify cycle with a non-default S-box exponent `d`,
+/// auto-deriving the round count via `required_rounds` instead of the
+/// crate's `MIMC_ROUNDS` (which is tuned for `d = 3`).
+fn run_with_exponent(d: u64) {
+    assert!(
+        is_valid_exponent(d),
+        "exponent {} is not a valid MiMC S-box (not coprime to l - 1)",
+        d
+    );
+    let mimc_rounds = required_rounds(d);
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(gens_capacity(mimc_rounds, d), 1);
+
+    let xl = Scalar::random(&mut rng);
+    let xr = Scalar::random(&mut rng);
+    let image = mimc(&xl, &xr, mimc_rounds, &constants, d);
+
+    let (proof, commitments) = {
+        let mut prover_transcript = Transcript::new(b"MiMC");
+        let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (com_l, var_l) = prover.commit(xl, Scalar::random(&mut rng));
+        let (com_r, var_r) = prover.commit(xr, Scalar::random(&mut rng));
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: Some(xl),
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: Some(xr),
+        };
+
+        assert!(mimc_gadget(
+            &mut prover,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            mimc_rounds,
+            &constants,
+            &image,
+            d
+        )
+        .is_ok());
+
+        (prover.prove(&bp_gens).unwrap(), (com_l, com_r))
+    };
+
+    let verification_result = {
+        let mut verifier_transcript = Transcript::new(b"MiMC");
+        let mut verifier = Verifier::new(&mut verifier_transcript);
+
+        let var_l = verifier.commit(commitments.0);
+        let var_r = verifier.commit(commitments.1);
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: None,
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: None,
+        };
+
+        assert!(mimc_gadget(
+            &mut verifier,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            mimc_rounds,
+            &constants,
+            &image,
+            d
+        )
+        .is_ok());
+
+        verifier.verify(&proof, &pc_gens, &bp_gens)
+    };
+
+    assert!(verification_result.is_ok());
+}
+
+#[test]
+fn test_run_with_exponent_cube() {
+    run_with_exponent(3);
+}
+
+#[test]
+fn test_run_with_exponent_quintic() {
+    run_with_exponent(5);
+}
+
 #[allow(dead_code)]
 fn benchmark(mimc_rounds: usize, samples: u32) {
+    benchmark_with_config(&BenchConfig::new(mimc_rounds, samples));
+}
+
+/// Runs `benchmark()`'s averaged prove/verify loop against a caller-supplied
+/// [`BenchConfig`]; see `run_with_config`.
+#[allow(dead_code)]
+pub fn benchmark_with_config(config: &BenchConfig) {
+    let mimc_rounds = config.rounds;
+    let samples = config.samples;
+
     // Define a source of randomness
     let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
 
@@ -143,7 +268,7 @@ fn benchmark(mimc_rounds: usize, samples: u32) {
     // Define the generators for the Pedersen commitments
     let pc_gens = PedersenGens::default();
     // Define the generators for the Bulletproofs
-    let bp_gens = BulletproofGens::new(2048, 1);
+    let bp_gens = BulletproofGens::new(gens_capacity(mimc_rounds, 3), 1);
 
     // Generate a random preimage
     let xl = Scalar::random(&mut rng);
@@ -153,7 +278,7 @@ fn benchmark(mimc_rounds: usize, samples: u32) {
     let mut total_verifying_time = Duration::new(0, 0);
     for _ in 0..samples {
         // Compute the MiMC hash image
-        let image = mimc(&xl, &xr, mimc_rounds, &constants);
+        let image = mimc(&xl, &xr, mimc_rounds, &constants, 3);
 
         // Create the proof including commitments
         let (proof, commitments) = {
@@ -179,7 +304,8 @@ fn benchmark(mimc_rounds: usize, samples: u32) {
                 right_alloc_scalar,
                 mimc_rounds,
                 &constants,
-                &image
+                &image,
+                3
             )
             .is_ok());
 
@@ -212,7 +338,8 @@ fn benchmark(mimc_rounds: usize, samples: u32) {
                 right_alloc_scalar,
                 mimc_rounds,
                 &constants,
-                &image
+                &image,
+                3
             )
             .is_ok());
 
@@ -247,13 +374,334 @@ fn test_benchmark() {
     benchmark(MIMC_ROUNDS, SAMPLES);
 }
 
-pub fn mimc(xl: &Scalar, xr: &Scalar, mimc_rounds: usize, constants: &[Scalar]) -> Scalar {
+/// Produces a single [`BenchRow`] for `config`, used by `crate::sweep::run`
+/// to assemble a CSV table across a grid of configs. One prove/verify cycle
+/// is timed, rather than averaged over `config.samples`.
+pub fn bench_row(config: &BenchConfig) -> BenchRow {
+    let mimc_rounds = config.rounds;
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(gens_capacity(mimc_rounds, 3), 1);
+
+    let xl = Scalar::random(&mut rng);
+    let xr = Scalar::random(&mut rng);
+    let image = mimc(&xl, &xr, mimc_rounds, &constants, 3);
+
+    let start = Instant::now();
+    let (proof, commitments) = {
+        let mut prover_transcript = Transcript::new(b"MiMC");
+        let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (com_l, var_l) = prover.commit(xl, Scalar::random(&mut rng));
+        let (com_r, var_r) = prover.commit(xr, Scalar::random(&mut rng));
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: Some(xl),
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: Some(xr),
+        };
+
+        assert!(mimc_gadget(
+            &mut prover,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            mimc_rounds,
+            &constants,
+            &image,
+            3
+        )
+        .is_ok());
+
+        (prover.prove(&bp_gens).unwrap(), (com_l, com_r))
+    };
+    let proving_secs = start.elapsed().as_secs_f64();
+
+    let proof_size_bytes = proof.serialized_size();
+    let (conjectured_security_bits, proven_security_bits) =
+        super::security::bulletproof_security_bits(mimc_rounds * 2);
+
+    let start = Instant::now();
+    let verification_result = {
+        let mut verifier_transcript = Transcript::new(b"MiMC");
+        let mut verifier = Verifier::new(&mut verifier_transcript);
+
+        let var_l = verifier.commit(commitments.0);
+        let var_r = verifier.commit(commitments.1);
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: None,
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: None,
+        };
+
+        assert!(mimc_gadget(
+            &mut verifier,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            mimc_rounds,
+            &constants,
+            &image,
+            3
+        )
+        .is_ok());
+
+        verifier.verify(&proof, &pc_gens, &bp_gens)
+    };
+    let verifying_secs = start.elapsed().as_secs_f64();
+    assert!(verification_result.is_ok());
+
+    BenchRow {
+        backend: "bulletproof".to_string(),
+        rounds: mimc_rounds,
+        proving_secs,
+        verifying_secs,
+        proof_size_bytes,
+        conjectured_security_bits,
+        proven_security_bits,
+    }
+}
+
+/// Proves and verifies `batch_size` independent MiMC statements, reporting
+/// the per-proof proving/verifying time at the given batch sizes.
+///
+/// KNOWN GAP, not the batching the originating request asked for: the
+/// request wanted all N verifications folded into a single
+/// `VartimeMultiscalarMul` check (as `snark::batch::verify_proofs_batch`
+/// does for Groth16's pairing check, accumulating into one aggregated
+/// equation). That requires reaching into the inner-product-argument terms
+/// `bulletproofs::r1cs::Verifier::verify` computes internally, which the
+/// public API does not expose -- it only returns a pass/fail `Result`, with
+/// no hook to fold its scalar multiplications across proofs. Absent an
+/// upstream API change (or forking the IPA verification logic out of the
+/// `bulletproofs` crate), this isn't implementable against the public R1CS
+/// API; flagging that back rather than silently shipping N independent
+/// `verify()` calls under an "amortized"/batched label. What follows is
+/// simply N independent proofs/verifications timed back to back, with no
+/// cost sharing between them -- each `Prover`/`Verifier` gets its own
+/// transcript labeled with its index in the batch, so a cheating prover
+/// can't replay one proof's challenges against another.
+#[allow(dead_code)]
+pub fn benchmark_batch(config: &BenchConfig, batch_sizes: &[usize]) {
+    let mimc_rounds = config.rounds;
+    let samples = config.samples;
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(gens_capacity(mimc_rounds, 3), 1);
+
+    for &n in batch_sizes {
+        let statements: Vec<(Scalar, Scalar, Scalar)> = (0..n)
+            .map(|_| {
+                let xl = Scalar::random(&mut rng);
+                let xr = Scalar::random(&mut rng);
+                let image = mimc(&xl, &xr, mimc_rounds, &constants, 3);
+                (xl, xr, image)
+            })
+            .collect();
+
+        let mut total_proving = Duration::new(0, 0);
+        let mut total_verifying = Duration::new(0, 0);
+        for _ in 0..samples {
+            for (i, &(xl, xr, image)) in statements.iter().enumerate() {
+                let start = Instant::now();
+                let (proof, commitments) = {
+                    let mut prover_transcript =
+                        Transcript::new(format!("MiMC batch proof {}", i).as_bytes());
+                    let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+                    let (com_l, var_l) = prover.commit(xl, Scalar::random(&mut rng));
+                    let (com_r, var_r) = prover.commit(xr, Scalar::random(&mut rng));
+                    let left_alloc_scalar = AllocatedScalar {
+                        variable: var_l,
+                        assignment: Some(xl),
+                    };
+                    let right_alloc_scalar = AllocatedScalar {
+                        variable: var_r,
+                        assignment: Some(xr),
+                    };
+
+                    assert!(mimc_gadget(
+                        &mut prover,
+                        left_alloc_scalar,
+                        right_alloc_scalar,
+                        mimc_rounds,
+                        &constants,
+                        &image,
+                        3
+                    )
+                    .is_ok());
+
+                    (prover.prove(&bp_gens).unwrap(), (com_l, com_r))
+                };
+                total_proving += start.elapsed();
+
+                let start = Instant::now();
+                let mut verifier_transcript =
+                    Transcript::new(format!("MiMC batch proof {}", i).as_bytes());
+                let mut verifier = Verifier::new(&mut verifier_transcript);
+
+                let var_l = verifier.commit(commitments.0);
+                let var_r = verifier.commit(commitments.1);
+                let left_alloc_scalar = AllocatedScalar {
+                    variable: var_l,
+                    assignment: None,
+                };
+                let right_alloc_scalar = AllocatedScalar {
+                    variable: var_r,
+                    assignment: None,
+                };
+
+                assert!(mimc_gadget(
+                    &mut verifier,
+                    left_alloc_scalar,
+                    right_alloc_scalar,
+                    mimc_rounds,
+                    &constants,
+                    &image,
+                    3
+                )
+                .is_ok());
+
+                assert!(verifier.verify(&proof, &pc_gens, &bp_gens).is_ok());
+                total_verifying += start.elapsed();
+            }
+        }
+
+        let total_samples = samples as usize * n;
+        let proving_avg = total_proving.as_secs_f64() / (total_samples as f64);
+        let verifying_avg = total_verifying.as_secs_f64() / (total_samples as f64);
+        println!(
+            "Batch size {}: per-proof proving {:?} seconds, verifying {:?} seconds (no cross-proof batching, see benchmark_batch's doc comment)",
+            n, proving_avg, verifying_avg
+        );
+    }
+}
+
+#[test]
+fn test_benchmark_batch() {
+    benchmark_batch(&BenchConfig::new(7, 1), &[1, 10, 100]);
+}
+
+// The curve25519 scalar field order `l`, used only to validate a candidate
+// MiMC S-box exponent `d` (it must be coprime to `l - 1` for `x -> x^d` to be
+// a permutation). Taken from the ed25519 spec: l = 2^252 +
+// 27742317777372353535851937790883648493.
+const SCALAR_FIELD_ORDER_HEX: &str =
+    "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+// Bit length of the scalar field, used by `required_rounds` to auto-derive a
+// secure round count for a given S-box exponent.
+const SCALAR_FIELD_BITS: u32 = 252;
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Returns `true` iff `x -> x^d` is a permutation of the scalar field, i.e.
+/// `d` is coprime to `l - 1` (the multiplicative group's order).
+pub fn is_valid_exponent(d: u64) -> bool {
+    if d < 2 {
+        return false;
+    }
+    let order_mod_d = SCALAR_FIELD_ORDER_HEX.chars().fold(0u64, |acc, c| {
+        let digit = c.to_digit(16).unwrap() as u64;
+        (acc * 16 + digit) % d
+    });
+    let order_minus_one_mod_d = (order_mod_d + d - 1) % d;
+    gcd(d, order_minus_one_mod_d) == 1
+}
+
+/// Secure round count for S-box exponent `d`. Each round only raises one
+/// branch of the 2-branch Feistel to the `d`-th power, so the scheme's
+/// algebraic degree grows half as fast per round as a full-width permutation
+/// would -- hence the factor of 2 here, rather than the naive `r =
+/// ceil(field_bits / log2(d))`. This reconciles with the crate's existing
+/// `MIMC_ROUNDS_BLS12_381 = 322` (see `snark.rs`): at a ~255-bit field and
+/// `d = 3`, `ceil(2 * 255 / log2(3)) = 322`, an exact match.
+pub fn required_rounds(d: u64) -> usize {
+    (2.0 * SCALAR_FIELD_BITS as f64 / (d as f64).log2()).ceil() as usize
+}
+
+// Computes `base^d` by repeated squaring; used both by `mimc` and (in
+// constraint form) by `pow_gadget`, so the two stay in lockstep.
+fn mimc_sbox(base: Scalar, d: u64) -> Scalar {
+    let mut result = Scalar::one();
+    let mut power = base;
+    let mut e = d;
+    while e > 0 {
+        if e & 1 == 1 {
+            result *= power;
+        }
+        power *= power;
+        e >>= 1;
+    }
+    result
+}
+
+/// Number of `cs.multiply` gates the square-and-multiply S-box for exponent
+/// `d` emits per round: one squaring per bit above the leading one, plus one
+/// multiply per additional set bit. For `d = 3` (binary `11`) this is 2,
+/// matching the crate's default cube S-box.
+pub fn multipliers_per_round(d: u64) -> usize {
+    let bit_length = 64 - d.leading_zeros() as usize;
+    let squarings = bit_length.saturating_sub(1);
+    let multiplies = (d.count_ones() as usize).saturating_sub(1);
+    squarings + multiplies
+}
+
+// Builds `base^d` via square-and-multiply, in lockstep with `mimc_sbox`.
+fn pow_gadget<CS: ConstraintSystem>(
+    cs: &mut CS,
+    base: LinearCombination,
+    d: u64,
+) -> LinearCombination {
+    let mut result: Option<LinearCombination> = None;
+    let mut power = base;
+    let mut e = d;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = Some(match result {
+                None => power.clone(),
+                Some(acc) => {
+                    let (_, _, o) = cs.multiply(acc, power.clone());
+                    o.into()
+                }
+            });
+        }
+        e >>= 1;
+        if e > 0 {
+            let (_, _, squared) = cs.multiply(power.clone(), power.clone());
+            power = squared.into();
+        }
+    }
+    result.expect("d must be >= 1")
+}
+
+/// MiMC hash with S-box exponent `d` (the crate uses `d = 3` everywhere
+/// except the `run_with_exponent` test below, via square-and-multiply).
+pub fn mimc(xl: &Scalar, xr: &Scalar, mimc_rounds: usize, constants: &[Scalar], d: u64) -> Scalar {
     let mut xl = xl.clone();
     let mut xr = xr.clone();
 
     for i in 0..mimc_rounds {
-        let tmp1 = xl + constants[i];
-        let mut tmp2 = (tmp1 * tmp1) * tmp1;
+        let base = xl + constants[i];
+        let mut tmp2 = mimc_sbox(base, d);
         tmp2 += xr;
         xr = xl;
         xl = tmp2;
@@ -269,6 +717,7 @@ pub fn mimc_gadget<CS: ConstraintSystem>(
     mimc_rounds: usize,
     mimc_constants: &[Scalar],
     image: &Scalar,
+    d: u64,
 ) -> Result<(), R1CSError> {
     let res_v = mimc_hash_2::<CS>(
         cs,
@@ -276,6 +725,7 @@ pub fn mimc_gadget<CS: ConstraintSystem>(
         right.variable.into(),
         mimc_rounds,
         mimc_constants,
+        d,
     )?;
     constrain_lc_with_scalar::<CS>(cs, res_v, image);
     Ok(())
@@ -287,12 +737,13 @@ pub fn mimc_hash_2<CS: ConstraintSystem>(
     right: LinearCombination,
     mimc_rounds: usize,
     mimc_constants: &[Scalar],
+    d: u64,
 ) -> Result<LinearCombination, R1CSError> {
     let mut left_v = left;
     let mut right_v = right;
 
     for j in 0..mimc_rounds {
-        // xL, xR := xR + (xL + Ci)^3, xL
+        // xL, xR := xR + (xL + Ci)^d, xL
         //let cs = &mut cs.namespace(|| format!("mimc round {}", j));
 
         let const_lc: LinearCombination =
@@ -300,10 +751,9 @@ pub fn mimc_hash_2<CS: ConstraintSystem>(
 
         let left_plus_const: LinearCombination = left_v.clone() + const_lc;
 
-        let (l, _, l_sqr) = cs.multiply(left_plus_const.clone(), left_plus_const);
-        let (_, _, l_cube) = cs.multiply(l_sqr.into(), l.into());
+        let sbox = pow_gadget(cs, left_plus_const, d);
 
-        let tmp = LinearCombination::from(l_cube) + right_v;
+        let tmp = sbox + right_v;
         right_v = left_v;
         left_v = tmp;
     }
@@ -0,0 +1,214 @@
+// Aggregates K independent MiMC preimage statements into a single
+// Bulletproofs R1CS proof. Each statement contributes its own pair of
+// committed (xl, xr) variables and its own copy of the `mimc_gadget`
+// constraints to one shared `Prover`/`Verifier` transcript, so a single
+// inner-product argument covers all K statements at once. Because that
+// argument's proof size grows logarithmically in the total number of
+// multiplication gates rather than linearly in K, the serialized proof
+// barely grows as K doubles, unlike K independent MiMC proofs.
+
+use super::*;
+use curve25519_dalek_ng::ristretto::CompressedRistretto;
+
+/// `GENS_CAPACITY` must cover the multiplication gates of all `k` MiMC
+/// instances; each instance uses `mimc_rounds * 2` multipliers and is given
+/// `(mimc_rounds + 1) * 2` generators (see the single-statement
+/// `GENS_CAPACITY` this mirrors).
+fn gens_capacity(k: usize, mimc_rounds: usize) -> usize {
+    k * (mimc_rounds + 1) * 2
+}
+
+/// Proves `k` independent MiMC preimage/image statements in one aggregated
+/// R1CS proof, returning the proof and the per-statement (xl, xr) Pedersen
+/// commitments in statement order.
+pub fn prove_aggregated(
+    k: usize,
+    mimc_rounds: usize,
+    constants: &[Scalar],
+    pc_gens: &PedersenGens,
+    bp_gens: &BulletproofGens,
+    rng: &mut StdRng,
+) -> (
+    bulletproofs::r1cs::R1CSProof,
+    Vec<(CompressedRistretto, CompressedRistretto)>,
+    Vec<Scalar>,
+) {
+    let mut prover_transcript = Transcript::new(b"MiMC-Aggregate");
+    let mut prover = Prover::new(pc_gens, &mut prover_transcript);
+
+    let mut commitments = Vec::with_capacity(k);
+    let mut images = Vec::with_capacity(k);
+
+    for _ in 0..k {
+        let xl = Scalar::random(&mut *rng);
+        let xr = Scalar::random(&mut *rng);
+        let image = mimc(&xl, &xr, mimc_rounds, constants, 3);
+
+        let (com_l, var_l) = prover.commit(xl, Scalar::random(&mut *rng));
+        let (com_r, var_r) = prover.commit(xr, Scalar::random(&mut *rng));
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: Some(xl),
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: Some(xr),
+        };
+
+        assert!(mimc_gadget(
+            &mut prover,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            mimc_rounds,
+            constants,
+            &image,
+            3
+        )
+        .is_ok());
+
+        commitments.push((com_l, com_r));
+        images.push(image);
+    }
+
+    let proof = prover.prove(bp_gens).unwrap();
+    (proof, commitments, images)
+}
+
+/// Verifies an aggregated proof produced by [`prove_aggregated`].
+pub fn verify_aggregated(
+    proof: &bulletproofs::r1cs::R1CSProof,
+    commitments: &[(CompressedRistretto, CompressedRistretto)],
+    images: &[Scalar],
+    mimc_rounds: usize,
+    constants: &[Scalar],
+    pc_gens: &PedersenGens,
+    bp_gens: &BulletproofGens,
+) -> Result<(), R1CSError> {
+    let mut verifier_transcript = Transcript::new(b"MiMC-Aggregate");
+    let mut verifier = Verifier::new(&mut verifier_transcript);
+
+    for (commitment, image) in commitments.iter().zip(images) {
+        let var_l = verifier.commit(commitment.0);
+        let var_r = verifier.commit(commitment.1);
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: None,
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: None,
+        };
+
+        mimc_gadget(
+            &mut verifier,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            mimc_rounds,
+            constants,
+            image,
+            3,
+        )?;
+    }
+
+    verifier.verify(proof, pc_gens, bp_gens)
+}
+
+pub fn run(k: usize) {
+    let mimc_rounds = MIMC_ROUNDS;
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(gens_capacity(k, mimc_rounds), 1);
+
+    let (proof, commitments, images) =
+        prove_aggregated(k, mimc_rounds, &constants, &pc_gens, &bp_gens, &mut rng);
+
+    let verification_result = verify_aggregated(
+        &proof,
+        &commitments,
+        &images,
+        mimc_rounds,
+        &constants,
+        &pc_gens,
+        &bp_gens,
+    );
+    assert!(verification_result.is_ok());
+
+    let serialized_proof_size_bytes = proof.serialized_size();
+    println!(
+        "Aggregated Bulletproof over {} MiMC statements: {} bytes serialized, {} bytes per statement",
+        k,
+        serialized_proof_size_bytes,
+        serialized_proof_size_bytes / k
+    );
+}
+
+#[test]
+fn test_run() {
+    run(4);
+}
+
+#[allow(dead_code)]
+fn benchmark(ks: &[usize], samples: u32) {
+    let mimc_rounds = MIMC_ROUNDS;
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let pc_gens = PedersenGens::default();
+
+    for &k in ks {
+        let bp_gens = BulletproofGens::new(gens_capacity(k, mimc_rounds), 1);
+
+        let mut total_proving_time = Duration::new(0, 0);
+        let mut total_verifying_time = Duration::new(0, 0);
+        let mut serialized_proof_size_bytes = 0;
+        for _ in 0..samples {
+            let start = Instant::now();
+            let (proof, commitments, images) =
+                prove_aggregated(k, mimc_rounds, &constants, &pc_gens, &bp_gens, &mut rng);
+            total_proving_time += start.elapsed();
+            serialized_proof_size_bytes = proof.serialized_size();
+
+            let start = Instant::now();
+            let verification_result = verify_aggregated(
+                &proof,
+                &commitments,
+                &images,
+                mimc_rounds,
+                &constants,
+                &pc_gens,
+                &bp_gens,
+            );
+            total_verifying_time += start.elapsed();
+            assert!(verification_result.is_ok());
+        }
+
+        let proving_avg = total_proving_time / samples;
+        let proving_avg =
+            proving_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (proving_avg.as_secs() as f64);
+        let verifying_avg = total_verifying_time / samples;
+        let verifying_avg = verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64
+            + (verifying_avg.as_secs() as f64);
+
+        println!(
+            "K = {}: proving {:?} s/stmt, verifying {:?} s/stmt, proof size {} bytes ({} bytes/stmt)",
+            k,
+            proving_avg / (k as f64),
+            verifying_avg / (k as f64),
+            serialized_proof_size_bytes,
+            serialized_proof_size_bytes / k
+        );
+    }
+}
+
+#[test]
+fn test_benchmark() {
+    benchmark(&[1, 2, 4, 8], SAMPLES);
+}
@@ -0,0 +1,209 @@
+// Proof-of-shuffle gadget: proves a committed vector `y` is a permutation of
+// a committed vector `x`. Unlike `mimc_gadget`, whose constraints are fully
+// known up front, this needs a challenge drawn *after* the commitments are
+// absorbed into the transcript, exercising Bulletproofs' two-phase
+// (randomized) constraint system via `specify_randomized_constraints` and
+// `challenge_scalar`.
+//
+// The check is the standard "equal multisets have equal products shifted by
+// a random point" trick: for a random challenge z, x is a permutation of y
+// iff prod(x_i - z) == prod(y_i - z), except with negligible probability
+// over the choice of z.
+
+use super::*;
+use bulletproofs::r1cs::{ConstraintSystem, R1CSError, RandomizableConstraintSystem, Variable};
+use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, SeedableRng};
+use std::time::{Duration, Instant};
+
+// GENS_CAPACITY for a shuffle of length k: k == 1 needs no multipliers at
+// all (just an equality constraint), otherwise each of the two product
+// chains (x and y) needs k - 1 multipliers.
+fn gens_capacity(k: usize) -> usize {
+    let multipliers = if k <= 1 { 0 } else { 2 * (k - 1) };
+    (multipliers + 1) * 2
+}
+
+/// Constrains `y` to be a permutation of `x` (both of length `k = x.len()`).
+/// The `k == 1` case is just an equality constraint; for `k >= 2` the two
+/// product chains `prod(x_i - z)` / `prod(y_i - z)` are built by folding
+/// `cs.multiply` from the last two elements down to index 0, and their
+/// outputs are constrained equal.
+pub fn shuffle_gadget<CS: RandomizableConstraintSystem>(
+    cs: &mut CS,
+    x: Vec<Variable>,
+    y: Vec<Variable>,
+) -> Result<(), R1CSError> {
+    assert_eq!(x.len(), y.len());
+    let k = x.len();
+
+    if k == 1 {
+        cs.constrain(y[0] - x[0]);
+        return Ok(());
+    }
+
+    cs.specify_randomized_constraints(move |cs| {
+        let z = cs.challenge_scalar(b"shuffle challenge");
+
+        let first_mulx_out = {
+            let (_, _, o) = cs.multiply(x[k - 1] - z, x[k - 2] - z);
+            o
+        };
+        let first_muly_out = {
+            let (_, _, o) = cs.multiply(y[k - 1] - z, y[k - 2] - z);
+            o
+        };
+
+        let last_mulx_out = (0..k - 2).rev().fold(first_mulx_out, |prev_out, i| {
+            let (_, _, o) = cs.multiply(prev_out.into(), x[i] - z);
+            o
+        });
+        let last_muly_out = (0..k - 2).rev().fold(first_muly_out, |prev_out, i| {
+            let (_, _, o) = cs.multiply(prev_out.into(), y[i] - z);
+            o
+        });
+
+        cs.constrain(last_mulx_out - last_muly_out);
+
+        Ok(())
+    })
+}
+
+pub fn run(k: usize) {
+    // Define a source of randomness
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let x: Vec<Scalar> = (0..k).map(|_| Scalar::random(&mut rng)).collect();
+    let mut y = x.clone();
+    y.shuffle(&mut rng);
+
+    // Define the generators for the Pedersen commitments
+    let pc_gens = PedersenGens::default();
+    // Define the generators for the Bulletproofs
+    let bp_gens = BulletproofGens::new(gens_capacity(k), 1);
+
+    let (proof, x_commitments, y_commitments) = {
+        let mut prover_transcript = Transcript::new(b"Shuffle");
+        let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (x_commitments, x_vars): (Vec<_>, Vec<_>) = x
+            .iter()
+            .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+            .unzip();
+        let (y_commitments, y_vars): (Vec<_>, Vec<_>) = y
+            .iter()
+            .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+            .unzip();
+
+        assert!(shuffle_gadget(&mut prover, x_vars, y_vars).is_ok());
+
+        println!(
+            "Shuffle of {} elements has the following prover metrics: {:?}",
+            k,
+            prover.metrics()
+        );
+
+        (prover.prove(&bp_gens).unwrap(), x_commitments, y_commitments)
+    };
+
+    let verification_result = {
+        let mut verifier_transcript = Transcript::new(b"Shuffle");
+        let mut verifier = Verifier::new(&mut verifier_transcript);
+
+        let x_vars: Vec<Variable> = x_commitments.iter().map(|c| verifier.commit(*c)).collect();
+        let y_vars: Vec<Variable> = y_commitments.iter().map(|c| verifier.commit(*c)).collect();
+
+        assert!(shuffle_gadget(&mut verifier, x_vars, y_vars).is_ok());
+
+        println!(
+            "Shuffle of {} elements has the following verifier metrics: {:?}",
+            k,
+            verifier.metrics()
+        );
+
+        verifier.verify(&proof, &pc_gens, &bp_gens)
+    };
+
+    assert!(verification_result.is_ok());
+
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes = proof.serialized_size();
+    let (conjectured_security_level, proven_security_level) =
+        super::super::security::bulletproof_security_bits(gens_capacity(k) / 2 - 1);
+    println!(
+        "Proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} \n\tSecurity level (bits): {} conjectured, {} proven",
+        runtime_proof_size_bytes, serilized_proof_size_bytes, conjectured_security_level, proven_security_level
+    );
+}
+
+#[test]
+fn test_run() {
+    run(8);
+}
+
+#[allow(dead_code)]
+fn benchmark(ks: &[usize], samples: u32) {
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let pc_gens = PedersenGens::default();
+
+    for &k in ks {
+        let x: Vec<Scalar> = (0..k).map(|_| Scalar::random(&mut rng)).collect();
+        let mut y = x.clone();
+        y.shuffle(&mut rng);
+
+        let bp_gens = BulletproofGens::new(gens_capacity(k), 1);
+
+        let mut total_proving = Duration::new(0, 0);
+        let mut total_verifying = Duration::new(0, 0);
+        for _ in 0..samples {
+            let start = Instant::now();
+            let (proof, x_commitments, y_commitments) = {
+                let mut prover_transcript = Transcript::new(b"Shuffle");
+                let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+                let (x_commitments, x_vars): (Vec<_>, Vec<_>) = x
+                    .iter()
+                    .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+                    .unzip();
+                let (y_commitments, y_vars): (Vec<_>, Vec<_>) = y
+                    .iter()
+                    .map(|v| prover.commit(*v, Scalar::random(&mut rng)))
+                    .unzip();
+
+                assert!(shuffle_gadget(&mut prover, x_vars, y_vars).is_ok());
+
+                (prover.prove(&bp_gens).unwrap(), x_commitments, y_commitments)
+            };
+            total_proving += start.elapsed();
+
+            let start = Instant::now();
+            let verification_result = {
+                let mut verifier_transcript = Transcript::new(b"Shuffle");
+                let mut verifier = Verifier::new(&mut verifier_transcript);
+
+                let x_vars: Vec<Variable> =
+                    x_commitments.iter().map(|c| verifier.commit(*c)).collect();
+                let y_vars: Vec<Variable> =
+                    y_commitments.iter().map(|c| verifier.commit(*c)).collect();
+
+                assert!(shuffle_gadget(&mut verifier, x_vars, y_vars).is_ok());
+
+                verifier.verify(&proof, &pc_gens, &bp_gens)
+            };
+            total_verifying += start.elapsed();
+            assert!(verification_result.is_ok());
+        }
+
+        let proving_avg = total_proving.as_secs_f64() / (samples as f64);
+        let verifying_avg = total_verifying.as_secs_f64() / (samples as f64);
+        println!(
+            "Shuffle size {}: average proving {:?} seconds, average verifying {:?} seconds",
+            k, proving_avg, verifying_avg
+        );
+    }
+}
+
+#[test]
+fn test_benchmark() {
+    benchmark(&[2, 4, 8, 16], SAMPLES);
+}
@@ -0,0 +1,320 @@
+// Serialize-to-disk / load-and-verify round trips for the CRS and proofs of
+// each backend. Real deployments separate the proving machine from the
+// verifier, so cold-start verification from serialized artifacts (which
+// pays a deserialization cost the in-memory benchmarks above never measure)
+// is a distinct, and worth benchmarking, workload.
+
+use super::*;
+use crate::bench_config::BenchConfig;
+use bellman::groth16::{Parameters, Proof as Groth16Proof};
+use bls12_381::Bls12;
+use bulletproofs::{
+    r1cs::{Prover as BulletproofProver, Verifier as BulletproofVerifier},
+    BulletproofGens, PedersenGens,
+};
+use curve25519_dalek_ng::scalar::Scalar as BulletproofScalar;
+use merlin::Transcript;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use winterfell::{
+    crypto::hashers::Blake3_256, crypto::DefaultRandomCoin, math::fields::f128::BaseElement,
+    AcceptableOptions, ProofOptions, Prover,
+};
+
+fn artifact_dir() -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join("nizkp-benchmark-artifacts");
+    let _ = std::fs::create_dir_all(&dir);
+    dir
+}
+
+// --- Groth16 -----------------------------------------------------------
+
+pub fn write_groth16_crs(path: &Path, crs: &Parameters<Bls12>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    crs.write(&mut file)
+}
+
+pub fn read_groth16_crs(path: &Path) -> io::Result<Parameters<Bls12>> {
+    let mut file = File::open(path)?;
+    Parameters::read(&mut file, true)
+}
+
+pub fn write_groth16_proof(path: &Path, proof: &Groth16Proof<Bls12>) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    proof.write(&mut file)
+}
+
+pub fn read_groth16_proof(path: &Path) -> io::Result<Groth16Proof<Bls12>> {
+    let mut file = File::open(path)?;
+    Groth16Proof::read(&mut file)
+}
+
+// --- STARK ---------------------------------------------------------------
+
+pub fn write_stark_proof(path: &Path, proof: &winterfell::StarkProof) -> io::Result<()> {
+    std::fs::write(path, proof.to_bytes())
+}
+
+pub fn read_stark_proof(path: &Path) -> io::Result<winterfell::StarkProof> {
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+    winterfell::StarkProof::from_bytes(&bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e)))
+}
+
+// --- Bulletproofs ----------------------------------------------------------
+
+pub fn write_bulletproof(path: &Path, proof: &bulletproofs::r1cs::R1CSProof) -> io::Result<()> {
+    std::fs::write(path, proof.to_bytes())
+}
+
+pub fn read_bulletproof(path: &Path) -> io::Result<bulletproofs::r1cs::R1CSProof> {
+    let mut bytes = Vec::new();
+    File::open(path)?.read_to_end(&mut bytes)?;
+    bulletproofs::r1cs::R1CSProof::from_bytes(&bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e)))
+}
+
+pub fn run() {
+    let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(RANDOMNESS_SEED);
+    let dir = artifact_dir();
+
+    // Groth16 round trip.
+    let mimc_rounds = snark::MIMC_ROUNDS_BLS12_381;
+    let constants = (0..mimc_rounds)
+        .map(|_| bls12_381::Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+    let crs = {
+        let circuit = snark::MiMCCircuit {
+            xl: None,
+            xr: None,
+            constants: &constants,
+        };
+        bellman::groth16::generate_random_parameters::<Bls12, _, _>(circuit, &mut rng).unwrap()
+    };
+    let xl = bls12_381::Scalar::random(&mut rng);
+    let xr = bls12_381::Scalar::random(&mut rng);
+    let image = snark::mimc(xl, xr, &constants);
+    let proof = {
+        let circuit = snark::MiMCCircuit {
+            xl: Some(xl),
+            xr: Some(xr),
+            constants: &constants,
+        };
+        bellman::groth16::create_random_proof(circuit, &crs, &mut rng).unwrap()
+    };
+
+    let crs_path = dir.join("groth16_crs.bin");
+    let proof_path = dir.join("groth16_proof.bin");
+    write_groth16_crs(&crs_path, &crs).unwrap();
+    write_groth16_proof(&proof_path, &proof).unwrap();
+
+    let start = Instant::now();
+    let loaded_crs = read_groth16_crs(&crs_path).unwrap();
+    let crs_deserialize_time = start.elapsed();
+
+    let start = Instant::now();
+    let loaded_proof = read_groth16_proof(&proof_path).unwrap();
+    let proof_deserialize_time = start.elapsed();
+
+    let pvk = bellman::groth16::prepare_verifying_key(&loaded_crs.vk);
+    assert!(bellman::groth16::verify_proof(&pvk, &loaded_proof, &[image]).is_ok());
+
+    println!(
+        "Groth16 persisted round-trip: CRS deserialize {:?}, proof deserialize {:?}",
+        crs_deserialize_time, proof_deserialize_time
+    );
+
+    // STARK round trip.
+    {
+        // `MiMCAir` regenerates the round-constant schedule from a fresh
+        // `RANDOMNESS_SEED` rng, so the trace's constants must come from one too.
+        let mut rng: rand::rngs::StdRng = rand::SeedableRng::from_seed(RANDOMNESS_SEED);
+        let config = BenchConfig::new(MIMC_ROUNDS, SAMPLES);
+        let options = ProofOptions::new(
+            config.num_queries,
+            config.blowup_factor,
+            config.grinding_factor,
+            config.field_extension,
+            config.fri_folding_factor,
+            config.fri_remainder_max_degree,
+        );
+        let acceptable_options = AcceptableOptions::OptionSet(vec![options.clone()]);
+        type Hasher = Blake3_256<BaseElement>;
+
+        let round_constants = (0..config.rounds)
+            .map(|_| BaseElement::new(rand::RngCore::next_u64(&mut rng) as u128))
+            .collect::<Vec<_>>();
+        let xl = BaseElement::new(rand::RngCore::next_u64(&mut rng) as u128);
+        let xr = BaseElement::new(rand::RngCore::next_u64(&mut rng) as u128);
+        let image = stark::mimc(xl, xr, &round_constants);
+
+        let prover = stark::MiMCProver::<Hasher>::new(options);
+        let trace = prover.build_trace(xl, xr, &round_constants);
+        let proof = prover.prove(trace).unwrap();
+
+        let stark_proof_path = dir.join("stark_proof.bin");
+        write_stark_proof(&stark_proof_path, &proof).unwrap();
+
+        let start = Instant::now();
+        let loaded_proof = read_stark_proof(&stark_proof_path).unwrap();
+        let stark_proof_deserialize_time = start.elapsed();
+
+        let pub_inputs = stark::PublicInputs { xl, xr, result: image };
+        assert!(winterfell::verify::<stark::MiMCAir, Hasher, DefaultRandomCoin<Hasher>>(
+            loaded_proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+        .is_ok());
+
+        println!(
+            "STARK persisted round-trip: proof deserialize {:?}",
+            stark_proof_deserialize_time
+        );
+    }
+
+    // Bulletproof round trip.
+    {
+        let mimc_rounds = MIMC_ROUNDS;
+        let constants = (0..mimc_rounds)
+            .map(|_| BulletproofScalar::random(&mut rng))
+            .collect::<Vec<_>>();
+
+        let pc_gens = PedersenGens::default();
+        // `bulletproof::gens_capacity` is private, so this mirrors its
+        // formula directly (two multipliers per round, plus one spare,
+        // doubled).
+        let bp_gens = BulletproofGens::new((mimc_rounds * 2 + 1) * 2, 1);
+
+        let xl = BulletproofScalar::random(&mut rng);
+        let xr = BulletproofScalar::random(&mut rng);
+        let image = bulletproof::mimc(&xl, &xr, mimc_rounds, &constants, 3);
+
+        let (proof, commitments) = {
+            let mut prover_transcript = Transcript::new(b"MiMC");
+            let mut prover = BulletproofProver::new(&pc_gens, &mut prover_transcript);
+
+            let (com_l, var_l) = prover.commit(xl, BulletproofScalar::random(&mut rng));
+            let (com_r, var_r) = prover.commit(xr, BulletproofScalar::random(&mut rng));
+            let left_alloc_scalar = bulletproof::AllocatedScalar {
+                variable: var_l,
+                assignment: Some(xl),
+            };
+            let right_alloc_scalar = bulletproof::AllocatedScalar {
+                variable: var_r,
+                assignment: Some(xr),
+            };
+
+            assert!(bulletproof::mimc_gadget(
+                &mut prover,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                mimc_rounds,
+                &constants,
+                &image,
+                3,
+            )
+            .is_ok());
+
+            (prover.prove(&bp_gens).unwrap(), (com_l, com_r))
+        };
+
+        let bulletproof_path = dir.join("bulletproof.bin");
+        write_bulletproof(&bulletproof_path, &proof).unwrap();
+
+        let start = Instant::now();
+        let loaded_proof = read_bulletproof(&bulletproof_path).unwrap();
+        let bulletproof_deserialize_time = start.elapsed();
+
+        let verification_result = {
+            let mut verifier_transcript = Transcript::new(b"MiMC");
+            let mut verifier = BulletproofVerifier::new(&mut verifier_transcript);
+
+            let var_l = verifier.commit(commitments.0);
+            let var_r = verifier.commit(commitments.1);
+            let left_alloc_scalar = bulletproof::AllocatedScalar {
+                variable: var_l,
+                assignment: None,
+            };
+            let right_alloc_scalar = bulletproof::AllocatedScalar {
+                variable: var_r,
+                assignment: None,
+            };
+
+            assert!(bulletproof::mimc_gadget(
+                &mut verifier,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                mimc_rounds,
+                &constants,
+                &image,
+                3,
+            )
+            .is_ok());
+
+            verifier.verify(&loaded_proof, &pc_gens, &bp_gens)
+        };
+        assert!(verification_result.is_ok());
+
+        println!(
+            "Bulletproof persisted round-trip: proof deserialize {:?}",
+            bulletproof_deserialize_time
+        );
+    }
+}
+
+#[test]
+fn test_run() {
+    run();
+}
+
+#[allow(dead_code)]
+fn benchmark_deserialize_groth16_proof(path: &Path, samples: u32) {
+    let mut total = Duration::new(0, 0);
+    for _ in 0..samples {
+        let start = Instant::now();
+        let _ = read_groth16_proof(path).unwrap();
+        total += start.elapsed();
+    }
+    let avg = total / samples;
+    let avg = avg.subsec_nanos() as f64 / 1_000_000_000f64 + (avg.as_secs() as f64);
+    println!(
+        "Average Groth16 proof deserialization time ({} samples): {:?} seconds",
+        samples, avg
+    );
+}
+
+#[allow(dead_code)]
+fn benchmark_deserialize_stark_proof(path: &Path, samples: u32) {
+    let mut total = Duration::new(0, 0);
+    for _ in 0..samples {
+        let start = Instant::now();
+        let _ = read_stark_proof(path).unwrap();
+        total += start.elapsed();
+    }
+    let avg = total / samples;
+    let avg = avg.subsec_nanos() as f64 / 1_000_000_000f64 + (avg.as_secs() as f64);
+    println!(
+        "Average STARK proof deserialization time ({} samples): {:?} seconds",
+        samples, avg
+    );
+}
+
+#[allow(dead_code)]
+fn benchmark_deserialize_bulletproof(path: &Path, samples: u32) {
+    let mut total = Duration::new(0, 0);
+    for _ in 0..samples {
+        let start = Instant::now();
+        let _ = read_bulletproof(path).unwrap();
+        total += start.elapsed();
+    }
+    let avg = total / samples;
+    let avg = avg.subsec_nanos() as f64 / 1_000_000_000f64 + (avg.as_secs() as f64);
+    println!(
+        "Average Bulletproof deserialization time ({} samples): {:?} seconds",
+        samples, avg
+    );
+}
@@ -0,0 +1,191 @@
+// Batched Groth16 proving and verification for the MiMC circuit.
+//
+// Verifying N proofs against the same verifying key can be done far more
+// cheaply than N independent `verify_proof` calls: instead of checking each
+// pairing equation
+//
+//   e(A_i, B_i) = e(alpha, beta) . e(sum_j S_ij . IC_j, gamma) . e(C_i, delta)
+//
+// separately, sample a random nonzero scalar r_i per proof and fold the
+// right-hand side terms (which are shared across proofs) into two group
+// accumulations, then check a single aggregated equation. The left-hand side
+// still needs one Miller loop per (A_i, B_i) pair (scaled by r_i, since the
+// pairing is only linear in one argument at a time), but the final
+// exponentiation is paid only once, and a single invalid proof flips the
+// aggregated check with overwhelming probability.
+
+use super::*;
+use bellman::groth16::{create_random_proof, Parameters, PreparedVerifyingKey, Proof};
+use bellman::SynthesisError;
+use bls12_381::{Bls12, G1Projective, G2Prepared, Scalar};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::{rngs::StdRng, SeedableRng};
+use std::time::{Duration, Instant};
+
+/// Creates `n` independent MiMC preimage/image proofs against the same CRS.
+pub fn create_random_proofs_batch(
+    n: usize,
+    crs: &Parameters<Bls12>,
+    constants: &[Scalar],
+    rng: &mut StdRng,
+) -> Vec<(Proof<Bls12>, Scalar)> {
+    (0..n)
+        .map(|_| {
+            let xl = Scalar::random(&mut *rng);
+            let xr = Scalar::random(&mut *rng);
+            let image = mimc(xl, xr, constants);
+
+            let circuit = MiMCCircuit {
+                xl: Some(xl),
+                xr: Some(xr),
+                constants,
+            };
+
+            (create_random_proof(circuit, crs, rng).unwrap(), image)
+        })
+        .collect()
+}
+
+/// Verifies a batch of Groth16 proofs against one verifying key by
+/// accumulating them into a single aggregated pairing equation.
+///
+/// Returns `Ok(true)` iff every proof in `proofs` is valid; a single invalid
+/// proof makes the aggregated equation fail except with negligible
+/// probability over the choice of `r_i`.
+pub fn verify_proofs_batch(
+    pvk: &PreparedVerifyingKey<Bls12>,
+    proofs: &[(Proof<Bls12>, Scalar)],
+    rng: &mut StdRng,
+) -> Result<bool, SynthesisError> {
+    let mut acc_ic = G1Projective::identity();
+    let mut acc_c = G1Projective::identity();
+    let mut acc_r = Scalar::zero();
+
+    let mut ml_terms: Vec<(bls12_381::G1Affine, G2Prepared)> = Vec::with_capacity(proofs.len() + 2);
+
+    for (proof, image) in proofs {
+        // A random nonzero weight per proof prevents an attacker from
+        // crafting proofs that cancel each other out in the aggregate.
+        let r = Scalar::random(&mut *rng);
+
+        // One public input (the MiMC image), so IC = ic[0] + image * ic[1].
+        let ic = pvk.ic[0].to_curve() + pvk.ic[1] * image;
+
+        acc_ic += ic * r;
+        acc_c += proof.c * r;
+        acc_r += r;
+
+        ml_terms.push(((proof.a * r).to_affine(), G2Prepared::from(proof.b)));
+    }
+
+    ml_terms.push((acc_ic.to_affine(), pvk.neg_gamma_g2.clone()));
+    ml_terms.push((acc_c.to_affine(), pvk.neg_delta_g2.clone()));
+
+    let terms: Vec<(&bls12_381::G1Affine, &G2Prepared)> =
+        ml_terms.iter().map(|(a, b)| (a, b)).collect();
+
+    let lhs = Bls12::multi_miller_loop(&terms).final_exponentiation();
+    let rhs = pvk.alpha_g1_beta_g2 * acc_r;
+
+    Ok(lhs == rhs)
+}
+
+pub fn run(batch_size: usize) {
+    let mimc_rounds = MIMC_ROUNDS_BLS12_381;
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let crs = {
+        let circuit = MiMCCircuit {
+            xl: None,
+            xr: None,
+            constants: &constants,
+        };
+
+        bellman::groth16::generate_random_parameters::<Bls12, _, _>(circuit, &mut rng).unwrap()
+    };
+    let pvk = bellman::groth16::prepare_verifying_key(&crs.vk);
+
+    let proofs = create_random_proofs_batch(batch_size, &crs, &constants, &mut rng);
+
+    let verification_result = verify_proofs_batch(&pvk, &proofs, &mut rng);
+    assert!(matches!(verification_result, Ok(true)));
+
+    println!(
+        "Batched SNARK verification ({} proofs): amortized and aggregate verification succeeded",
+        batch_size
+    );
+}
+
+#[test]
+fn test_run() {
+    run(10);
+}
+
+#[allow(dead_code)]
+fn benchmark(batch_sizes: &[usize], samples: u32) {
+    let mimc_rounds = MIMC_ROUNDS_BLS12_381;
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let constants = (0..mimc_rounds)
+        .map(|_| Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let crs = {
+        let circuit = MiMCCircuit {
+            xl: None,
+            xr: None,
+            constants: &constants,
+        };
+
+        bellman::groth16::generate_random_parameters::<Bls12, _, _>(circuit, &mut rng).unwrap()
+    };
+    let pvk = bellman::groth16::prepare_verifying_key(&crs.vk);
+
+    for &n in batch_sizes {
+        let proofs = create_random_proofs_batch(n, &crs, &constants, &mut rng);
+
+        let mut total_verifying = Duration::new(0, 0);
+        for _ in 0..samples {
+            let start = Instant::now();
+            let verification_result = verify_proofs_batch(&pvk, &proofs, &mut rng);
+            total_verifying += start.elapsed();
+            assert!(matches!(verification_result, Ok(true)));
+        }
+
+        let verifying_avg = total_verifying / samples;
+        let verifying_avg = verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64
+            + (verifying_avg.as_secs() as f64);
+        let per_proof = verifying_avg / (n as f64);
+
+        // Linear baseline: n independent `verify_proof` calls, the thing the
+        // aggregated check above is trying to beat.
+        let mut total_linear = Duration::new(0, 0);
+        for _ in 0..samples {
+            let start = Instant::now();
+            for (proof, image) in &proofs {
+                assert!(bellman::groth16::verify_proof(&pvk, proof, &[*image]).is_ok());
+            }
+            total_linear += start.elapsed();
+        }
+        let linear_avg = total_linear / samples;
+        let linear_avg =
+            linear_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (linear_avg.as_secs() as f64);
+        let linear_per_proof = linear_avg / (n as f64);
+
+        println!(
+            "Batch size {}: amortized batched per-proof {:?} seconds vs. linear per-proof {:?} seconds",
+            n, per_proof, linear_per_proof
+        );
+    }
+}
+
+#[test]
+fn test_benchmark() {
+    benchmark(&[1, 10, 100], SAMPLES);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::bench_config::{BenchConfig, BenchRow};
 use rand::{rngs::StdRng, RngCore, SeedableRng};
 use std::{
     marker::PhantomData,
@@ -9,7 +10,7 @@ use std::{
 use winterfell::crypto::hashers::{Blake3_192, Blake3_256, Sha3_256};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher},
-    math::{fields::f128::BaseElement, FieldElement, ToElements},
+    math::{fields::f128::BaseElement, ExtensionOf, FieldElement, ToElements},
     matrix::ColMatrix,
     Air, AirContext, Assertion, AuxTraceRandElements, ConstraintCompositionCoefficients,
     DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, FieldExtension, ProofOptions,
@@ -17,25 +18,32 @@ use winterfell::{
 };
 
 const TRACE_WIDTH: usize = 3;
-// NOTE: the domain size is trace_length * options.blowup_factor, where the trace_length is lower for lower MIMC_ROUNDS.
+const AUX_TRACE_WIDTH: usize = 1;
+// NOTE: the domain size is trace_length * options.blowup_factor, where the trace_length is lower for lower rounds counts.
 // If the "number of values must be smaller than domain size" occurs we should increase the blowup_factor.
 // A blowup_factor of 8 works for the mimimum number of MiMC rounds (8), while 4 is already enough for 16 rounds, etc.
-const NUM_QUERIES: usize = 42; // must not be > 255
-const BLOWUP_FACTOR: usize = 8; // must be a power of two and must not be > 128
-const GRINDING_FACTOR: u32 = 16; // must not be > 32
-const FIELD_EXTENSION: FieldExtension = FieldExtension::None;
-const FRI_FOLDING_FACTOR: usize = 8; // must be 2, 4, 8, or 16
-const FRI_REMAINDER_MAX_DEGREE: usize = 31; // must be a power of two -1 and must not be > 255
+
+fn proof_options(config: &BenchConfig) -> ProofOptions {
+    ProofOptions::new(
+        config.num_queries,
+        config.blowup_factor,
+        config.grinding_factor,
+        config.field_extension,
+        config.fri_folding_factor,
+        config.fri_remainder_max_degree,
+    )
+}
 
 pub fn run() {
-    let options = ProofOptions::new(
-        NUM_QUERIES,
-        BLOWUP_FACTOR,
-        GRINDING_FACTOR,
-        FIELD_EXTENSION,
-        FRI_FOLDING_FACTOR,
-        FRI_REMAINDER_MAX_DEGREE,
-    );
+    run_with_config(&BenchConfig::new(MIMC_ROUNDS, SAMPLES));
+}
+
+/// Runs `run()`'s single prove/verify cycle against a caller-supplied
+/// [`BenchConfig`] instead of the crate's compile-time defaults, so the
+/// round count and Winterfell proof options can be explored without
+/// recompiling (see `crate::sweep`).
+pub fn run_with_config(config: &BenchConfig) {
+    let options = proof_options(config);
     let acceptable_options = winterfell::AcceptableOptions::OptionSet(vec![options.clone()]);
     type Hasher = Blake3_256<BaseElement>;
 
@@ -43,10 +51,9 @@ pub fn run() {
     let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
 
     // Generate the MiMC round constants
-    let mut round_constants = [BaseElement::ZERO; MIMC_ROUNDS];
-    for i in 0..round_constants.len() {
-        round_constants[i] = BaseElement::new(rng.next_u64() as u128);
-    }
+    let round_constants = (0..config.rounds)
+        .map(|_| BaseElement::new(rng.next_u64() as u128))
+        .collect::<Vec<_>>();
 
     // Generate a random preimage
     let rand_xl: u64 = rng.next_u64();
@@ -71,14 +78,11 @@ pub fn run() {
     let proven_security_level = proof.security_level::<Hasher>(false);
     let conjectured_security_level = proof.security_level::<Hasher>(true);
 
-    // Verify that the proof is valid
+    // Verify that the proof is valid. The round constants are no longer part
+    // of `PublicInputs` -- see `MiMCAir::new` and `get_periodic_column_values`
+    // for how the verifier recovers and checks them instead.
     let verification_result = {
-        let pub_inputs = PublicInputs {
-            xl,
-            xr,
-            result: image,
-            round_constants,
-        };
+        let pub_inputs = PublicInputs { xl, xr, result: image };
 
         winterfell::verify::<MiMCAir, Hasher, DefaultRandomCoin<Hasher>>(
             proof,
@@ -102,15 +106,15 @@ fn test_run() {
 }
 
 #[allow(dead_code)]
-pub fn benchmark(_mimc_rounds: usize, samples: u32) {
-    let options = ProofOptions::new(
-        NUM_QUERIES,
-        BLOWUP_FACTOR,
-        GRINDING_FACTOR,
-        FIELD_EXTENSION,
-        FRI_FOLDING_FACTOR,
-        FRI_REMAINDER_MAX_DEGREE,
-    );
+pub fn benchmark(mimc_rounds: usize, samples: u32) {
+    benchmark_with_config(&BenchConfig::new(mimc_rounds, samples));
+}
+
+/// Runs `benchmark()`'s averaged prove/verify loop against a caller-supplied
+/// [`BenchConfig`]; see `run_with_config`.
+#[allow(dead_code)]
+pub fn benchmark_with_config(config: &BenchConfig) {
+    let options = proof_options(config);
     let acceptable_options = winterfell::AcceptableOptions::OptionSet(vec![options.clone()]);
     type Hasher = Blake3_256<BaseElement>;
 
@@ -118,10 +122,9 @@ pub fn benchmark(_mimc_rounds: usize, samples: u32) {
     let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
 
     // Generate the MiMC round constants
-    let mut round_constants = [BaseElement::ZERO; MIMC_ROUNDS];
-    for i in 0..round_constants.len() {
-        round_constants[i] = BaseElement::new(rng.next_u64() as u128);
-    }
+    let round_constants = (0..config.rounds)
+        .map(|_| BaseElement::new(rng.next_u64() as u128))
+        .collect::<Vec<_>>();
 
     // Generate a random preimage
     let rand_xl: u64 = rng.next_u64();
@@ -131,7 +134,7 @@ pub fn benchmark(_mimc_rounds: usize, samples: u32) {
 
     let mut total_proving = Duration::new(0, 0);
     let mut total_verifying = Duration::new(0, 0);
-    for _ in 0..samples {
+    for _ in 0..config.samples {
         // Compute the MiMC hash image
         let image = mimc(xl, xr, &round_constants);
 
@@ -150,12 +153,7 @@ pub fn benchmark(_mimc_rounds: usize, samples: u32) {
 
         // Verify that the proof is valid
         let verification_result = {
-            let pub_inputs = PublicInputs {
-                xl,
-                xr,
-                result: image,
-                round_constants,
-            };
+            let pub_inputs = PublicInputs { xl, xr, result: image };
 
             let start = Instant::now();
 
@@ -172,20 +170,20 @@ pub fn benchmark(_mimc_rounds: usize, samples: u32) {
         assert!(verification_result.is_ok());
     }
 
-    let proving_avg = total_proving / samples;
+    let proving_avg = total_proving / config.samples;
     let proving_avg =
         proving_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (proving_avg.as_secs() as f64);
     println!(
         "Average proving time ({} samples): {:?} seconds",
-        samples, proving_avg
+        config.samples, proving_avg
     );
 
-    let verifying_avg = total_verifying / samples;
+    let verifying_avg = total_verifying / config.samples;
     let verifying_avg =
         verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (verifying_avg.as_secs() as f64);
     println!(
         "Average verifying time ({} samples): {:?} seconds",
-        samples, verifying_avg
+        config.samples, verifying_avg
     );
 }
 
@@ -194,26 +192,101 @@ fn test_benchmark() {
     benchmark(MIMC_ROUNDS, SAMPLES);
 }
 
+/// Produces a single [`BenchRow`] for `config`, used by `crate::sweep::run`
+/// to assemble a CSV table across a grid of configs. One prove/verify cycle
+/// is timed, rather than averaged over `config.samples`, since a sweep is
+/// typically already exploring many configs.
+pub fn bench_row(config: &BenchConfig) -> BenchRow {
+    let options = proof_options(config);
+    let acceptable_options = winterfell::AcceptableOptions::OptionSet(vec![options.clone()]);
+    type Hasher = Blake3_256<BaseElement>;
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let round_constants = (0..config.rounds)
+        .map(|_| BaseElement::new(rng.next_u64() as u128))
+        .collect::<Vec<_>>();
+
+    let xl = BaseElement::new(rng.next_u64() as u128);
+    let xr = BaseElement::new(rng.next_u64() as u128);
+    let image = mimc(xl, xr, &round_constants);
+
+    let start = Instant::now();
+    let proof = {
+        let prover = MiMCProver::<Hasher>::new(options.clone());
+        let trace = prover.build_trace(xl, xr, &round_constants);
+        prover.prove(trace).unwrap()
+    };
+    let proving_secs = start.elapsed().as_secs_f64();
+
+    let proof_size_bytes = proof.to_bytes().len();
+    let conjectured_security_bits = proof.security_level::<Hasher>(true);
+    let proven_security_bits = proof.security_level::<Hasher>(false);
+
+    let start = Instant::now();
+    let pub_inputs = PublicInputs { xl, xr, result: image };
+    let verification_result = winterfell::verify::<MiMCAir, Hasher, DefaultRandomCoin<Hasher>>(
+        proof,
+        pub_inputs,
+        &acceptable_options,
+    );
+    let verifying_secs = start.elapsed().as_secs_f64();
+    assert!(verification_result.is_ok());
+
+    BenchRow {
+        backend: "stark".to_string(),
+        rounds: config.rounds,
+        proving_secs,
+        verifying_secs,
+        proof_size_bytes,
+        conjectured_security_bits,
+        proven_security_bits,
+    }
+}
+
+// Round constants no longer travel through `PublicInputs` -- only `xl`, `xr`
+// and the resulting hash do. The verifier recovers the expected constant
+// schedule itself (see `MiMCAir::new` / `get_periodic_column_values`) since
+// it is fully determined by the crate's public `RANDOMNESS_SEED`, and the
+// randomized-AIR permutation check in `evaluate_aux_transition` proves that
+// the *committed* main-trace round-constant column actually used during
+// proving matches that schedule.
 pub struct PublicInputs {
     pub xl: BaseElement,
     pub xr: BaseElement,
     pub result: BaseElement,
-    pub round_constants: [BaseElement; MIMC_ROUNDS],
 }
 
 impl ToElements<BaseElement> for PublicInputs {
     fn to_elements(&self) -> Vec<BaseElement> {
-        let mut result = vec![self.xl, self.xr, self.result];
-        result.extend_from_slice(&self.round_constants);
-        result
+        vec![self.xl, self.xr, self.result]
     }
 }
 
+/// Builds the full per-row schedule of expected round constants, padded with
+/// a trailing zero to match `build_trace`'s unused final-step constant, so
+/// the result always spans the whole trace length. Used both as the AIR's
+/// periodic column (see `MiMCAir::get_periodic_column_values`) and to build
+/// the auxiliary running-product trace (`build_aux_trace`).
+fn expected_round_constants(mimc_rounds: usize) -> Vec<BaseElement> {
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let mut constants: Vec<BaseElement> = (0..mimc_rounds)
+        .map(|_| BaseElement::new(rng.next_u64() as u128))
+        .collect();
+    constants.push(BaseElement::ZERO);
+    constants
+}
+
 pub struct MiMCAir {
     context: AirContext<BaseElement>,
     xl: BaseElement,
     xr: BaseElement,
     result: BaseElement,
+    // The publicly-known expected schedule, independently reconstructed by
+    // both the prover and the verifier from `RANDOMNESS_SEED` -- never sent
+    // over the wire as part of the proof or `PublicInputs`. Doubles as the
+    // AIR's periodic column (`get_periodic_column_values`), so its length
+    // always spans the whole trace (which can vary, see `BenchConfig::rounds`).
+    expected_round_constants: Vec<BaseElement>,
 }
 
 impl Air for MiMCAir {
@@ -227,6 +300,10 @@ impl Air for MiMCAir {
     // that an instance of our computation is a specific invocation of the do_work() function.
     fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
         assert_eq!(TRACE_WIDTH, trace_info.width());
+        let trace_length = trace_info.length();
+        // The round count is read off the trace itself rather than a
+        // compile-time constant, so `BenchConfig::rounds` can vary freely.
+        let mimc_rounds = trace_length - 1;
 
         // Our computation requires a single transition constraint. The constraint itself
         // is defined in the evaluate_transition() method below, but here we need to specify
@@ -238,17 +315,33 @@ impl Air for MiMCAir {
             TransitionConstraintDegree::new(1), // second transition is degree 1, since we just compare (no multiplications)
         ];
 
+        // Permutation-argument running product over (alpha + committed_ci) vs.
+        // (alpha + expected_ci); see `evaluate_aux_transition`. `expected_ci`
+        // comes from the periodic round-constant column below, whose cycle
+        // spans the whole trace (it never actually repeats), so Winterfell
+        // must be told its cycle length to size the constraint's evaluation
+        // degree correctly.
+        let aux_degrees = vec![TransitionConstraintDegree::with_cycles(3, vec![trace_length])];
+
         // We also need to specify the exact number of assertions we will place against the
         // execution trace. This number must be the same as the number of items in a vector
         // returned from the get_assertions() method below.
         let num_assertions = 3;
+        let num_aux_assertions = 2;
 
         MiMCAir {
-            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            context: AirContext::new_multi_segment(
+                trace_info,
+                degrees,
+                aux_degrees,
+                num_assertions,
+                num_aux_assertions,
+                options,
+            ),
             xl: pub_inputs.xl,
             xr: pub_inputs.xr,
             result: pub_inputs.result,
-            // round_constants: pub_inputs.round_constants,
+            expected_round_constants: expected_round_constants(mimc_rounds),
         }
     }
 
@@ -281,6 +374,43 @@ impl Air for MiMCAir {
         result[1] += are_equal(next_xr, expected_xr);
     }
 
+    // Randomized-AIR check, run once the verifier's random `alpha` is known:
+    // proves the committed main-trace `ci` column is a reordering-free match
+    // of `expected_round_constants`, via the running product
+    // `z[i+1] * (alpha + expected_ci[i]) == z[i] * (alpha + committed_ci[i])`,
+    // started and ended at the public value 1 (see `get_aux_assertions`).
+    // A single mismatched round constant makes the final product differ from
+    // 1 except with negligible probability over the choice of `alpha`.
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        let alpha = aux_rand_elements.rand_elements(0)[0];
+
+        let committed_ci = main_frame.current()[2];
+        // The expected round constant at this row comes straight from the
+        // periodic column Winterfell itself low-degree-extends and evaluates
+        // (including at the out-of-domain/FRI query points this method is
+        // actually called at) -- not from reverse-engineering a value lookup
+        // against the concrete trace values, which only matches at the
+        // literal trace rows and silently falls back to round 0 everywhere
+        // else.
+        let expected_ci = periodic_values[0];
+
+        let z_current = aux_frame.current()[0];
+        let z_next = aux_frame.next()[0];
+
+        result[0] += z_next * (alpha + E::from(expected_ci))
+            - z_current * (alpha + E::from(committed_ci));
+    }
+
     // Here, we'll define a set of assertions about the execution trace which must be satisfied
     // for the computation to be valid. Essentially, this ties computation's execution trace
     // to the public inputs.
@@ -296,11 +426,30 @@ impl Air for MiMCAir {
         assertions
     }
 
+    fn get_aux_assertions<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, E::ONE),
+            Assertion::single(0, last_step, E::ONE),
+        ]
+    }
+
     // This is just boilerplate which is used by the Winterfell prover/verifier to retrieve
     // the context of the computation.
     fn context(&self) -> &AirContext<Self::BaseField> {
         &self.context
     }
+
+    // Registers `expected_round_constants` as a periodic column so Winterfell
+    // low-degree-extends it itself and hands `evaluate_aux_transition` the
+    // correctly-evaluated value at every constraint-evaluation point, rather
+    // than leaving the AIR to recover it by inspecting trace values.
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        vec![self.expected_round_constants.clone()]
+    }
 }
 
 pub struct MiMCProver<H: ElementHasher> {
@@ -322,8 +471,7 @@ impl<H: ElementHasher> MiMCProver<H> {
         xr: BaseElement,
         round_constants: &[BaseElement],
     ) -> TraceTable<BaseElement> {
-        let mimc_rounds = MIMC_ROUNDS;
-        debug_assert_eq!(mimc_rounds, round_constants.len());
+        let mimc_rounds = round_constants.len();
         // NOTE: trace_length must always be a power of 2 and >= 8
         let trace_length = mimc_rounds + 1;
         debug_assert!(trace_length >= 8);
@@ -379,17 +527,12 @@ where
 
     fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
         let last_step = trace.length() - 1;
-        let mut round_constants = [BaseElement::ZERO; MIMC_ROUNDS];
-        for i in 0..(trace.length() - 1) {
-            round_constants[i] = trace.get(2, i);
-        }
 
         PublicInputs {
             xl: trace.get(0, 0),
             xr: trace.get(1, 0),
             // result image is the xl of the last step.
             result: trace.get(0, last_step),
-            round_constants,
         }
     }
 
@@ -414,6 +557,97 @@ where
     ) -> Self::ConstraintEvaluator<'a, E> {
         DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
     }
+
+    // Builds the aux segment's running-product column from the main trace's
+    // committed round-constant column and the verifier-supplied `alpha`; see
+    // `MiMCAir::evaluate_aux_transition`.
+    fn build_aux_trace<E>(
+        &self,
+        main_trace: &Self::Trace,
+        aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let alpha = aux_rand_elements.rand_elements(0)[0];
+        let trace_length = main_trace.length();
+        let expected = expected_round_constants(trace_length - 1);
+
+        let mut column = vec![E::ONE; trace_length];
+        for step in 0..(trace_length - 1) {
+            let committed_ci = main_trace.get(2, step);
+            let expected_ci = expected[step];
+            let ratio = (alpha + E::from(committed_ci)) / (alpha + E::from(expected_ci));
+            column[step + 1] = column[step] * ratio;
+        }
+
+        ColMatrix::new(vec![column])
+    }
+}
+
+/// Proves and verifies `batch_size` independent MiMC statements, reporting
+/// the amortized per-proof verification time. Winterfell has no analogue of
+/// Groth16's aggregated-pairing batching (`snark::batch`), so "together"
+/// here means what it realistically can: N independently generated proofs,
+/// timed as a batch so the per-proof cost (including any fixed overhead) is
+/// comparable across backends at the same batch sizes.
+#[allow(dead_code)]
+pub fn benchmark_batch(config: &BenchConfig, batch_sizes: &[usize], samples: u32) {
+    type Hasher = Blake3_256<BaseElement>;
+    let options = proof_options(config);
+    let acceptable_options = winterfell::AcceptableOptions::OptionSet(vec![options.clone()]);
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+    let round_constants = (0..config.rounds)
+        .map(|_| BaseElement::new(rng.next_u64() as u128))
+        .collect::<Vec<_>>();
+
+    for &n in batch_sizes {
+        let statements: Vec<(BaseElement, BaseElement, BaseElement)> = (0..n)
+            .map(|_| {
+                let xl = BaseElement::new(rng.next_u64() as u128);
+                let xr = BaseElement::new(rng.next_u64() as u128);
+                let image = mimc(xl, xr, &round_constants);
+                (xl, xr, image)
+            })
+            .collect();
+
+        let mut total_proving = Duration::new(0, 0);
+        let mut total_verifying = Duration::new(0, 0);
+        for _ in 0..samples {
+            for &(xl, xr, image) in &statements {
+                let prover = MiMCProver::<Hasher>::new(options.clone());
+
+                let start = Instant::now();
+                let trace = prover.build_trace(xl, xr, &round_constants);
+                let proof = prover.prove(trace).unwrap();
+                total_proving += start.elapsed();
+
+                let pub_inputs = PublicInputs { xl, xr, result: image };
+                let start = Instant::now();
+                let verification_result = winterfell::verify::<
+                    MiMCAir,
+                    Hasher,
+                    DefaultRandomCoin<Hasher>,
+                >(proof, pub_inputs, &acceptable_options);
+                total_verifying += start.elapsed();
+                assert!(verification_result.is_ok());
+            }
+        }
+
+        let total_samples = samples as usize * n;
+        let proving_avg = total_proving.as_secs_f64() / (total_samples as f64);
+        let verifying_avg = total_verifying.as_secs_f64() / (total_samples as f64);
+        println!(
+            "Batch size {}: amortized per-proof proving {:?} seconds, verifying {:?} seconds",
+            n, proving_avg, verifying_avg
+        );
+    }
+}
+
+#[test]
+fn test_benchmark_batch() {
+    benchmark_batch(&BenchConfig::new(31, 1), &[1, 10, 100], 1);
 }
 
 // are_equal returns zero only when a == b.
@@ -422,11 +656,7 @@ pub fn are_equal<E: FieldElement>(a: E, b: E) -> E {
 }
 
 // compute_mimc_hash computes a MiMC hash
-pub fn mimc(
-    xl: BaseElement,
-    xr: BaseElement,
-    round_constants: &[BaseElement; MIMC_ROUNDS],
-) -> BaseElement {
+pub fn mimc(xl: BaseElement, xr: BaseElement, round_constants: &[BaseElement]) -> BaseElement {
     let mut xl = xl.clone();
     let mut xr = xr.clone();
 
@@ -0,0 +1,31 @@
+pub mod bulletproof;
+pub mod snark;
+pub mod stark;
+
+// State width (number of field elements mixed per permutation). A width of 3
+// matches the MiMC circuit's 2-element preimage plus one "capacity" element,
+// keeping the two hashes directly comparable at the same arity.
+pub const T: usize = 3;
+// Full rounds are split evenly before and after the partial rounds.
+pub const FULL_ROUNDS: usize = 8;
+// FULL_ROUNDS + PARTIAL_ROUNDS = 63 = 2^6 - 1, so the STARK trace length
+// (rounds + 1) comes out to the required power of two.
+pub const PARTIAL_ROUNDS: usize = 55;
+
+pub const RANDOMNESS_SEED: [u8; 32] = [24u8; 32];
+#[allow(dead_code)]
+pub const SAMPLES: u32 = 50;
+
+pub fn run() {
+    println!("Proving and verifying Poseidon (zk-SNARK)...");
+    snark::run();
+    println!("Finished proving and verifying Poseidon (zk-SNARK)!");
+
+    println!("Proving and verifying Poseidon (Bulletproof)...");
+    bulletproof::run();
+    println!("Finished proving and verifying Poseidon (Bulletproof)!");
+
+    println!("Proving and verifying Poseidon (zk-STARK)...");
+    stark::run();
+    println!("Finished proving and verifying Poseidon (zk-STARK)!");
+}
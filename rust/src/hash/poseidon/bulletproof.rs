@@ -0,0 +1,378 @@
+// Poseidon hash gadget and proving/verification benchmark for the
+// Bulletproofs backend, parallel to `hash::mimc::bulletproof`.
+
+use super::*;
+use crate::hash::mimc::bulletproof::{constrain_lc_with_scalar, AllocatedScalar};
+use bulletproofs::{
+    r1cs::{ConstraintSystem, LinearCombination, Prover, R1CSError, Variable, Verifier},
+    BulletproofGens, PedersenGens,
+};
+use curve25519_dalek_ng::scalar::Scalar;
+use merlin::Transcript;
+use rand::{rngs::StdRng, SeedableRng};
+use std::time::{Duration, Instant};
+
+// Each full round costs 3 multiplication constraints per state element (for
+// `x^5`, via x^2, x^4, x^5); each partial round costs 3 for the single
+// S-boxed element. The MDS mix is a linear combination and free in the R1CS
+// cost model.
+const MULTIPLIERS_PER_PROOF: usize = (FULL_ROUNDS * T + PARTIAL_ROUNDS) * 3;
+const GENS_CAPACITY: usize = MULTIPLIERS_PER_PROOF * 2;
+
+// Generates a t x t MDS matrix over the field as a Cauchy matrix: pick 2*t
+// distinct-with-overwhelming-probability field elements xs, ys and set
+// M[i][j] = 1 / (xs[i] + ys[j]). A Cauchy matrix is MDS (every square
+// submatrix is nonsingular) whenever the xs are pairwise distinct, the ys
+// are pairwise distinct, and no xs[i] equals any ys[j] -- all but certain
+// here since Scalar is drawn from a ~252-bit field.
+//
+// This gadget (and its hardcoded-MDS bug, fixed by deriving the matrix here
+// rather than hardcoding a constant) was introduced by chunk1-4, which
+// first brought up the dual-backend Poseidon subsystem; the fix just
+// happened to land in a commit tagged chunk2-6 because that's the request
+// being worked when the bug was noticed. chunk2-6 itself asked for this
+// same Bulletproof `poseidon_hash_2` gadget again (its request overlaps
+// chunk1-4's), but since chunk1-4 already shipped one, chunk2-6's own
+// commit added a Groth16 Poseidon backend (`poseidon/snark.rs`) instead of
+// a second copy of this file.
+fn generate_mds(rng: &mut StdRng) -> [[Scalar; T]; T] {
+    let mut xs = [Scalar::zero(); T];
+    let mut ys = [Scalar::zero(); T];
+    for x in xs.iter_mut() {
+        *x = Scalar::random(&mut *rng);
+    }
+    for y in ys.iter_mut() {
+        *y = Scalar::random(&mut *rng);
+    }
+
+    let mut mds = [[Scalar::zero(); T]; T];
+    for i in 0..T {
+        for j in 0..T {
+            mds[i][j] = (xs[i] + ys[j]).invert();
+        }
+    }
+    mds
+}
+
+pub fn run() {
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let round_constants = generate_round_constants(&mut rng);
+    let mds = generate_mds(&mut rng);
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(GENS_CAPACITY, 1);
+
+    let xl = Scalar::random(&mut rng);
+    let xr = Scalar::random(&mut rng);
+    let image = poseidon(&xl, &xr, &round_constants, &mds);
+
+    let (proof, commitments) = {
+        let mut prover_transcript = Transcript::new(b"Poseidon");
+        let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+        let (com_l, var_l) = prover.commit(xl, Scalar::random(&mut rng));
+        let (com_r, var_r) = prover.commit(xr, Scalar::random(&mut rng));
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: Some(xl),
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: Some(xr),
+        };
+
+        assert!(poseidon_gadget(
+            &mut prover,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            &round_constants,
+            &mds,
+            &image
+        )
+        .is_ok());
+
+        println!(
+            "Poseidon hash ({} full, {} partial rounds) has the following prover metrics: {:?}",
+            FULL_ROUNDS,
+            PARTIAL_ROUNDS,
+            &prover.metrics()
+        );
+
+        (prover.prove(&bp_gens).unwrap(), (com_l, com_r))
+    };
+
+    let verification_result = {
+        let mut verifier_transcript = Transcript::new(b"Poseidon");
+        let mut verifier = Verifier::new(&mut verifier_transcript);
+
+        let var_l = verifier.commit(commitments.0);
+        let var_r = verifier.commit(commitments.1);
+        let left_alloc_scalar = AllocatedScalar {
+            variable: var_l,
+            assignment: None,
+        };
+        let right_alloc_scalar = AllocatedScalar {
+            variable: var_r,
+            assignment: None,
+        };
+
+        assert!(poseidon_gadget(
+            &mut verifier,
+            left_alloc_scalar,
+            right_alloc_scalar,
+            &round_constants,
+            &mds,
+            &image
+        )
+        .is_ok());
+
+        verifier.verify(&proof, &pc_gens, &bp_gens)
+    };
+
+    assert!(verification_result.is_ok());
+
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes = proof.serialized_size();
+    let (conjectured_security_level, proven_security_level) =
+        crate::hash::mimc::security::bulletproof_security_bits(MULTIPLIERS_PER_PROOF);
+    println!(
+        "Proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} \n\tSecurity level (bits): {} conjectured, {} proven",
+        runtime_proof_size_bytes, serilized_proof_size_bytes, conjectured_security_level, proven_security_level
+    );
+}
+
+#[test]
+fn test_run() {
+    run();
+}
+
+#[allow(dead_code)]
+fn benchmark(samples: u32) {
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let round_constants = generate_round_constants(&mut rng);
+    let mds = generate_mds(&mut rng);
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(GENS_CAPACITY, 1);
+
+    let xl = Scalar::random(&mut rng);
+    let xr = Scalar::random(&mut rng);
+
+    let mut total_proving_time = Duration::new(0, 0);
+    let mut total_verifying_time = Duration::new(0, 0);
+    for _ in 0..samples {
+        let image = poseidon(&xl, &xr, &round_constants, &mds);
+
+        let (proof, commitments) = {
+            let mut prover_transcript = Transcript::new(b"Poseidon");
+            let mut prover = Prover::new(&pc_gens, &mut prover_transcript);
+
+            let start = Instant::now();
+
+            let (com_l, var_l) = prover.commit(xl, Scalar::random(&mut rng));
+            let (com_r, var_r) = prover.commit(xr, Scalar::random(&mut rng));
+            let left_alloc_scalar = AllocatedScalar {
+                variable: var_l,
+                assignment: Some(xl),
+            };
+            let right_alloc_scalar = AllocatedScalar {
+                variable: var_r,
+                assignment: Some(xr),
+            };
+
+            assert!(poseidon_gadget(
+                &mut prover,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                &round_constants,
+                &mds,
+                &image
+            )
+            .is_ok());
+
+            let proof = prover.prove(&bp_gens).unwrap();
+            total_proving_time += start.elapsed();
+
+            (proof, (com_l, com_r))
+        };
+
+        let verification_result = {
+            let mut verifier_transcript = Transcript::new(b"Poseidon");
+            let mut verifier = Verifier::new(&mut verifier_transcript);
+
+            let var_l = verifier.commit(commitments.0);
+            let var_r = verifier.commit(commitments.1);
+            let left_alloc_scalar = AllocatedScalar {
+                variable: var_l,
+                assignment: None,
+            };
+            let right_alloc_scalar = AllocatedScalar {
+                variable: var_r,
+                assignment: None,
+            };
+
+            let start = Instant::now();
+            assert!(poseidon_gadget(
+                &mut verifier,
+                left_alloc_scalar,
+                right_alloc_scalar,
+                &round_constants,
+                &mds,
+                &image
+            )
+            .is_ok());
+
+            let verification_result = verifier.verify(&proof, &pc_gens, &bp_gens);
+            total_verifying_time += start.elapsed();
+
+            verification_result
+        };
+
+        assert!(verification_result.is_ok());
+    }
+
+    let proving_avg = total_proving_time / samples;
+    let proving_avg =
+        proving_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (proving_avg.as_secs() as f64);
+    println!(
+        "Average proving time ({} samples): {:?} seconds",
+        samples, proving_avg
+    );
+
+    let verifying_avg = total_verifying_time / samples;
+    let verifying_avg =
+        verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (verifying_avg.as_secs() as f64);
+    println!(
+        "Average verifying time ({} samples): {:?} seconds",
+        samples, verifying_avg
+    );
+}
+
+#[test]
+fn test_benchmark() {
+    benchmark(SAMPLES);
+}
+
+fn generate_round_constants(rng: &mut StdRng) -> Vec<[Scalar; T]> {
+    (0..(FULL_ROUNDS + PARTIAL_ROUNDS))
+        .map(|_| {
+            let mut rc = [Scalar::zero(); T];
+            for slot in rc.iter_mut() {
+                *slot = Scalar::random(&mut *rng);
+            }
+            rc
+        })
+        .collect()
+}
+
+fn is_full_round(round: usize) -> bool {
+    round < FULL_ROUNDS / 2 || round >= FULL_ROUNDS / 2 + PARTIAL_ROUNDS
+}
+
+pub fn poseidon(
+    xl: &Scalar,
+    xr: &Scalar,
+    round_constants: &[[Scalar; T]],
+    mds: &[[Scalar; T]; T],
+) -> Scalar {
+    let mut state = [*xl, *xr, Scalar::zero()];
+
+    for (round, rc) in round_constants.iter().enumerate() {
+        let full = is_full_round(round);
+
+        let mut after_sbox = [Scalar::zero(); T];
+        for i in 0..T {
+            let y = state[i] + rc[i];
+            after_sbox[i] = if full || i == 0 { y * y * y * y * y } else { y };
+        }
+
+        for (i, row) in mds.iter().enumerate() {
+            let mut acc = Scalar::zero();
+            for (j, coeff) in row.iter().enumerate() {
+                acc += *coeff * after_sbox[j];
+            }
+            state[i] = acc;
+        }
+    }
+
+    state[0]
+}
+
+pub fn poseidon_gadget<CS: ConstraintSystem>(
+    cs: &mut CS,
+    left: AllocatedScalar,
+    right: AllocatedScalar,
+    round_constants: &[[Scalar; T]],
+    mds: &[[Scalar; T]; T],
+    image: &Scalar,
+) -> Result<(), R1CSError> {
+    let res_v = poseidon_hash_2::<CS>(
+        cs,
+        left.variable.into(),
+        right.variable.into(),
+        round_constants,
+        mds,
+    )?;
+    constrain_lc_with_scalar::<CS>(cs, res_v, image);
+    Ok(())
+}
+
+pub fn poseidon_hash_2<CS: ConstraintSystem>(
+    cs: &mut CS,
+    left: LinearCombination,
+    right: LinearCombination,
+    round_constants: &[[Scalar; T]],
+    mds: &[[Scalar; T]; T],
+) -> Result<LinearCombination, R1CSError> {
+    let mut state = [left, right, LinearCombination::from(Scalar::zero())];
+
+    for (round, rc) in round_constants.iter().enumerate() {
+        let full = is_full_round(round);
+        state = poseidon_round_gadget::<CS>(cs, state, rc, mds, full)?;
+    }
+
+    let [out, _, _] = state;
+    Ok(out)
+}
+
+fn poseidon_round_gadget<CS: ConstraintSystem>(
+    cs: &mut CS,
+    state: [LinearCombination; T],
+    round_constants: &[Scalar; T],
+    mds: &[[Scalar; T]; T],
+    full: bool,
+) -> Result<[LinearCombination; T], R1CSError> {
+    let mut after_sbox: Vec<LinearCombination> = Vec::with_capacity(T);
+
+    for (i, s) in state.iter().enumerate() {
+        let const_lc: LinearCombination =
+            vec![(Variable::One(), round_constants[i])].iter().collect();
+        let y: LinearCombination = s.clone() + const_lc;
+
+        if full || i == 0 {
+            let (l, _, y_sqr) = cs.multiply(y.clone(), y);
+            let (_, _, y_quartic) = cs.multiply(y_sqr.into(), y_sqr.into());
+            let (_, _, y_quintic) = cs.multiply(y_quartic.into(), l.into());
+            after_sbox.push(y_quintic.into());
+        } else {
+            after_sbox.push(y);
+        }
+    }
+
+    let mut new_state: Vec<LinearCombination> = Vec::with_capacity(T);
+    for row in mds.iter() {
+        let mut acc = LinearCombination::from(Scalar::zero());
+        for (j, coeff) in row.iter().enumerate() {
+            acc = acc + after_sbox[j].clone() * *coeff;
+        }
+        new_state.push(acc);
+    }
+
+    Ok([
+        new_state[0].clone(),
+        new_state[1].clone(),
+        new_state[2].clone(),
+    ])
+}
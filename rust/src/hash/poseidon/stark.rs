@@ -0,0 +1,473 @@
+use super::*;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::{
+    marker::PhantomData,
+    time::{Duration, Instant},
+    vec,
+};
+#[allow(unused_imports)]
+use winterfell::crypto::hashers::{Blake3_192, Blake3_256, Sha3_256};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher},
+    math::{fields::f128::BaseElement, FieldElement, ToElements},
+    matrix::ColMatrix,
+    Air, AirContext, Assertion, AuxTraceRandElements, ConstraintCompositionCoefficients,
+    DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, FieldExtension, ProofOptions,
+    Prover, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable, TransitionConstraintDegree,
+};
+
+const TRACE_WIDTH: usize = T; // state only -- round constants and the full/partial selector are periodic columns, not witnessed
+const TOTAL_ROUNDS: usize = FULL_ROUNDS + PARTIAL_ROUNDS;
+const NUM_QUERIES: usize = 42; // must not be > 255
+const BLOWUP_FACTOR: usize = 8; // must be a power of two and must not be > 128
+const GRINDING_FACTOR: u32 = 16; // must not be > 32
+const FIELD_EXTENSION: FieldExtension = FieldExtension::None;
+const FRI_FOLDING_FACTOR: usize = 8; // must be 2, 4, 8, or 16
+const FRI_REMAINDER_MAX_DEGREE: usize = 31; // must be a power of two -1 and must not be > 255
+
+// A fixed, small MDS matrix. This is not the result of a cryptographic
+// search for an optimal MDS candidate (as a production Poseidon instance
+// would use) -- it is just a simple integer matrix with nonzero determinant,
+// good enough for benchmarking prover/verifier cost rather than for
+// deployment.
+const MDS: [[u64; T]; T] = [[2, 1, 1], [1, 2, 1], [1, 1, 2]];
+
+fn mds_mix(state: [BaseElement; T]) -> [BaseElement; T] {
+    let mut out = [BaseElement::ZERO; T];
+    for (i, row) in MDS.iter().enumerate() {
+        let mut acc = BaseElement::ZERO;
+        for (j, coeff) in row.iter().enumerate() {
+            acc += BaseElement::new(*coeff as u128) * state[j];
+        }
+        out[i] = acc;
+    }
+    out
+}
+
+// Applies one Poseidon round (add round constants, S-box, MDS mix) to
+// `state`, where `full` selects between applying `x^5` to every element
+// (a full round) or only to `state[0]` (a partial round).
+fn poseidon_round(
+    state: [BaseElement; T],
+    round_constants: [BaseElement; T],
+    full: bool,
+) -> [BaseElement; T] {
+    let mut after_sbox = [BaseElement::ZERO; T];
+    for i in 0..T {
+        let y = state[i] + round_constants[i];
+        after_sbox[i] = if full || i == 0 {
+            y * y * y * y * y
+        } else {
+            y
+        };
+    }
+    mds_mix(after_sbox)
+}
+
+fn is_full_round(round: usize) -> bool {
+    round < FULL_ROUNDS / 2 || round >= FULL_ROUNDS / 2 + PARTIAL_ROUNDS
+}
+
+pub fn poseidon(xl: BaseElement, xr: BaseElement, round_constants: &[[BaseElement; T]]) -> BaseElement {
+    let mut state = [xl, xr, BaseElement::ZERO];
+    for (round, rc) in round_constants.iter().enumerate() {
+        state = poseidon_round(state, *rc, is_full_round(round));
+    }
+    state[0]
+}
+
+fn generate_round_constants(rng: &mut StdRng) -> Vec<[BaseElement; T]> {
+    (0..TOTAL_ROUNDS)
+        .map(|_| {
+            let mut rc = [BaseElement::ZERO; T];
+            for slot in rc.iter_mut() {
+                *slot = BaseElement::new(rng.next_u64() as u128);
+            }
+            rc
+        })
+        .collect()
+}
+
+/// Splits the per-round constants into `T` periodic columns (one per state
+/// element) plus a full/partial selector column, each padded with a
+/// trailing zero so its length matches the trace (the last row's constants
+/// are never used, same as `build_trace`'s final step). These are the
+/// AIR's periodic columns (see `PoseidonAir::get_periodic_column_values`):
+/// publicly known from `RANDOMNESS_SEED`, low-degree-extended by Winterfell
+/// itself, and fed straight into `evaluate_transition` -- never a witnessed
+/// trace column a prover could pick freely.
+fn periodic_columns(round_constants: &[[BaseElement; T]]) -> Vec<Vec<BaseElement>> {
+    let mut columns: Vec<Vec<BaseElement>> = vec![Vec::with_capacity(round_constants.len() + 1); T + 1];
+    for (round, rc) in round_constants.iter().enumerate() {
+        for i in 0..T {
+            columns[i].push(rc[i]);
+        }
+        columns[T].push(if is_full_round(round) {
+            BaseElement::ONE
+        } else {
+            BaseElement::ZERO
+        });
+    }
+    for column in columns.iter_mut() {
+        column.push(BaseElement::ZERO);
+    }
+    columns
+}
+
+pub fn run() {
+    let options = ProofOptions::new(
+        NUM_QUERIES,
+        BLOWUP_FACTOR,
+        GRINDING_FACTOR,
+        FIELD_EXTENSION,
+        FRI_FOLDING_FACTOR,
+        FRI_REMAINDER_MAX_DEGREE,
+    );
+    let acceptable_options = winterfell::AcceptableOptions::OptionSet(vec![options.clone()]);
+    type Hasher = Blake3_256<BaseElement>;
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let round_constants = generate_round_constants(&mut rng);
+
+    let rand_xl: u64 = rng.next_u64();
+    let xl = BaseElement::new(rand_xl as u128);
+    let rand_xr: u64 = rng.next_u64();
+    let xr = BaseElement::new(rand_xr as u128);
+
+    let image = poseidon(xl, xr, &round_constants);
+
+    let proof = {
+        let prover = PoseidonProver::<Hasher>::new(options.clone());
+
+        let trace = prover.build_trace(xl, xr, &round_constants);
+        prover.prove(trace).unwrap()
+    };
+
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes = proof.to_bytes().len();
+    let proven_security_level = proof.security_level::<Hasher>(false);
+    let conjectured_security_level = proof.security_level::<Hasher>(true);
+
+    let verification_result = {
+        let pub_inputs = PublicInputs {
+            xl,
+            xr,
+            result: image,
+        };
+
+        winterfell::verify::<PoseidonAir, Hasher, DefaultRandomCoin<Hasher>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    };
+
+    assert!(verification_result.is_ok());
+
+    println!(
+        "Proof metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {} \n\tSecurity level (bits): {} conjectured, {} proven",
+        runtime_proof_size_bytes, serilized_proof_size_bytes, conjectured_security_level, proven_security_level
+    );
+}
+
+#[test]
+fn test_run() {
+    run();
+}
+
+#[allow(dead_code)]
+pub fn benchmark(samples: u32) {
+    let options = ProofOptions::new(
+        NUM_QUERIES,
+        BLOWUP_FACTOR,
+        GRINDING_FACTOR,
+        FIELD_EXTENSION,
+        FRI_FOLDING_FACTOR,
+        FRI_REMAINDER_MAX_DEGREE,
+    );
+    let acceptable_options = winterfell::AcceptableOptions::OptionSet(vec![options.clone()]);
+    type Hasher = Blake3_256<BaseElement>;
+
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let round_constants = generate_round_constants(&mut rng);
+
+    let rand_xl: u64 = rng.next_u64();
+    let xl = BaseElement::new(rand_xl as u128);
+    let rand_xr: u64 = rng.next_u64();
+    let xr = BaseElement::new(rand_xr as u128);
+
+    let mut total_proving = Duration::new(0, 0);
+    let mut total_verifying = Duration::new(0, 0);
+    for _ in 0..samples {
+        let image = poseidon(xl, xr, &round_constants);
+
+        let proof = {
+            let prover = PoseidonProver::<Hasher>::new(options.clone());
+
+            let start = Instant::now();
+            let trace = prover.build_trace(xl, xr, &round_constants);
+            let proof = prover.prove(trace).unwrap();
+            total_proving += start.elapsed();
+
+            proof
+        };
+
+        let verification_result = {
+            let pub_inputs = PublicInputs {
+                xl,
+                xr,
+                result: image,
+            };
+
+            let start = Instant::now();
+            let verification_result = winterfell::verify::<
+                PoseidonAir,
+                Hasher,
+                DefaultRandomCoin<Hasher>,
+            >(proof, pub_inputs, &acceptable_options);
+            total_verifying += start.elapsed();
+
+            verification_result
+        };
+
+        assert!(verification_result.is_ok());
+    }
+
+    let proving_avg = total_proving / samples;
+    let proving_avg =
+        proving_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (proving_avg.as_secs() as f64);
+    println!(
+        "Average proving time ({} samples): {:?} seconds",
+        samples, proving_avg
+    );
+
+    let verifying_avg = total_verifying / samples;
+    let verifying_avg =
+        verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (verifying_avg.as_secs() as f64);
+    println!(
+        "Average verifying time ({} samples): {:?} seconds",
+        samples, verifying_avg
+    );
+}
+
+#[test]
+fn test_benchmark() {
+    benchmark(SAMPLES);
+}
+
+pub struct PublicInputs {
+    pub xl: BaseElement,
+    pub xr: BaseElement,
+    pub result: BaseElement,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.xl, self.xr, self.result]
+    }
+}
+
+pub struct PoseidonAir {
+    context: AirContext<BaseElement>,
+    xl: BaseElement,
+    xr: BaseElement,
+    result: BaseElement,
+    // The publicly-known round-constant/selector schedule, independently
+    // reconstructed by both the prover and the verifier from
+    // `RANDOMNESS_SEED` -- never witnessed as a trace column a prover could
+    // pick freely. Doubles as the AIR's periodic columns
+    // (`get_periodic_column_values`).
+    periodic_columns: Vec<Vec<BaseElement>>,
+}
+
+impl Air for PoseidonAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let trace_length = trace_info.length();
+
+        let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+        let round_constants = generate_round_constants(&mut rng);
+        let periodic_columns = periodic_columns(&round_constants);
+
+        // The S-box (`x^5`) makes the per-element constraint degree 5; mixing
+        // it with the (periodic) full/partial selector to pick between the
+        // full- and partial-round formula raises the overall transition
+        // degree to 6. See `poseidon_round` for the formula this mirrors.
+        // Each constraint references all `T` round-constant columns plus the
+        // selector column, none of which ever repeat within the trace, so
+        // every cycle length is the full trace length.
+        let cycles = vec![trace_length; T + 1];
+        let degrees = vec![
+            TransitionConstraintDegree::with_cycles(6, cycles.clone()),
+            TransitionConstraintDegree::with_cycles(6, cycles.clone()),
+            TransitionConstraintDegree::with_cycles(6, cycles),
+        ];
+
+        let num_assertions = 4;
+
+        PoseidonAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            xl: pub_inputs.xl,
+            xr: pub_inputs.xr,
+            result: pub_inputs.result,
+            periodic_columns,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+
+        let state = [current[0], current[1], current[2]];
+        let round_constants = [periodic_values[0], periodic_values[1], periodic_values[2]];
+        let sel = periodic_values[3];
+        let one = E::ONE;
+
+        let mut after_sbox = [E::ZERO; T];
+        for i in 0..T {
+            let y = state[i] + round_constants[i];
+            let y5 = y * y * y * y * y;
+            after_sbox[i] = if i == 0 {
+                y5
+            } else {
+                sel * y5 + (one - sel) * y
+            };
+        }
+
+        for (i, row) in MDS.iter().enumerate() {
+            let mut expected = E::ZERO;
+            for (j, coeff) in row.iter().enumerate() {
+                expected += E::from(BaseElement::new(*coeff as u128)) * after_sbox[j];
+            }
+            result[i] += are_equal(next[i], expected);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, self.xl),
+            Assertion::single(1, 0, self.xr),
+            Assertion::single(2, 0, BaseElement::ZERO),
+            Assertion::single(0, last_step, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    // Registers the round-constant/selector schedule as periodic columns so
+    // Winterfell low-degree-extends it itself and hands `evaluate_transition`
+    // the correctly-evaluated values at every constraint-evaluation point,
+    // rather than trusting a witnessed trace column a prover could pick to
+    // make the transition hold for any chosen next state.
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        self.periodic_columns.clone()
+    }
+}
+
+pub struct PoseidonProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PoseidonProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            _hasher: PhantomData,
+        }
+    }
+
+    pub fn build_trace(
+        &self,
+        xl: BaseElement,
+        xr: BaseElement,
+        round_constants: &[[BaseElement; T]],
+    ) -> TraceTable<BaseElement> {
+        debug_assert_eq!(TOTAL_ROUNDS, round_constants.len());
+        // NOTE: trace_length must always be a power of 2 and >= 8
+        let trace_length = TOTAL_ROUNDS + 1;
+        debug_assert!(trace_length >= 8);
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                state[0] = xl;
+                state[1] = xr;
+                state[2] = BaseElement::ZERO;
+            },
+            |step, state| {
+                let current = [state[0], state[1], state[2]];
+                let new_state =
+                    poseidon_round(current, round_constants[step], is_full_round(step));
+
+                state[0] = new_state[0];
+                state[1] = new_state[1];
+                state[2] = new_state[2];
+            },
+        );
+
+        trace
+    }
+}
+
+impl<H: ElementHasher> Prover for PoseidonProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement>,
+{
+    type BaseField = BaseElement;
+    type Air = PoseidonAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> = DefaultTraceLde<E, Self::HashFn>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs {
+            xl: trace.get(0, 0),
+            xr: trace.get(1, 0),
+            result: trace.get(0, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: AuxTraceRandElements<E>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+fn are_equal<E: FieldElement>(a: E, b: E) -> E {
+    a - b
+}
@@ -1,6 +1,11 @@
+mod bench_config;
 mod hash;
+mod range;
+mod sweep;
 
-use nizkp_benchmark::hash::mimc::{bulletproof, snark, stark};
+use bench_config::BenchConfig;
+use nizkp_benchmark::hash::mimc::{bulletproof, snark, spartan, stark};
+use nizkp_benchmark::hash::poseidon;
 
 fn main() {
     println!("\n------------------------------------------------------------------------\n");
@@ -22,4 +27,45 @@ fn main() {
     println!("Bulletproof MiMC hash done!");
 
     println!("\n------------------------------------------------------------------------\n");
+
+    println!("Running aggregated Bulletproof MiMC hash...");
+    bulletproof::aggregate::run(8);
+    println!("Aggregated Bulletproof MiMC hash done!");
+
+    println!("\n------------------------------------------------------------------------\n");
+
+    println!("Running transparent (Spartan-style) NIZK MiMC hash...");
+    spartan::run();
+    println!("Transparent (Spartan-style) NIZK MiMC hash done!");
+
+    println!("\n------------------------------------------------------------------------\n");
+
+    println!("Running Poseidon hash...");
+    poseidon::run();
+    println!("Poseidon hash done!");
+
+    println!("\n------------------------------------------------------------------------\n");
+
+    println!("Running Bulletproof range proofs...");
+    nizkp_benchmark::range::bulletproof::run();
+    println!("Bulletproof range proofs done!");
+
+    println!("\n------------------------------------------------------------------------\n");
+
+    println!("Running aggregated Bulletproof range proofs...");
+    nizkp_benchmark::range::bulletproof::mpc::run();
+    println!("Aggregated Bulletproof range proofs done!");
+
+    println!("\n------------------------------------------------------------------------\n");
+
+    println!("Running MiMC parameter sweep (STARK vs. Bulletproof)...");
+    let sweep_configs: Vec<BenchConfig> = [31, 63, 127]
+        .iter()
+        .map(|&rounds| BenchConfig::new(rounds, 1))
+        .collect();
+    let sweep_rows = sweep::run(&sweep_configs);
+    print!("{}", sweep::to_csv(&sweep_rows));
+    println!("MiMC parameter sweep done!");
+
+    println!("\n------------------------------------------------------------------------\n");
 }
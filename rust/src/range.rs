@@ -0,0 +1,15 @@
+pub mod bulletproof;
+
+pub const RANDOMNESS_SEED: [u8; 32] = [24u8; 32];
+#[allow(dead_code)]
+pub const SAMPLES: u32 = 50;
+
+pub fn run() {
+    println!("Proving and verifying Bulletproof range proofs...");
+    bulletproof::run();
+    println!("Finished proving and verifying Bulletproof range proofs!");
+
+    println!("Proving and verifying aggregated Bulletproof range proofs...");
+    bulletproof::mpc::run();
+    println!("Finished proving and verifying aggregated Bulletproof range proofs!");
+}
@@ -0,0 +1,142 @@
+// Bulletproofs range proofs: proving a committed value lies in [0, 2^n)
+// without a trusted setup. This is the headline Bulletproofs workload and is
+// deliberately kept separate from the MiMC hash benchmark so the two can be
+// contrasted: MiMC exercises a deep, purely sequential R1CS circuit, while a
+// range proof is shallow but scales with the bit-width n.
+
+pub mod mpc;
+
+use super::*;
+use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
+use curve25519_dalek_ng::scalar::Scalar;
+use merlin::Transcript;
+use rand::{rngs::StdRng, SeedableRng};
+use std::time::{Duration, Instant};
+
+const BIT_WIDTHS: [usize; 4] = [8, 16, 32, 64];
+
+pub fn run() {
+    for n in BIT_WIDTHS {
+        run_for_bitwidth(n);
+    }
+}
+
+fn run_for_bitwidth(n: usize) {
+    // Define a source of randomness
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    // Define the generators for the Pedersen commitments and the range proof.
+    // One party, n bits of capacity.
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(n, 1);
+
+    // Secret value in [0, 2^n)
+    let secret_value: u64 = if n == 64 {
+        u64::MAX / 2
+    } else {
+        (1u64 << (n - 1)) + 1
+    };
+    let blinding = Scalar::random(&mut rng);
+
+    // Create the proof and its Pedersen commitment
+    let (proof, commitment) = {
+        let mut prover_transcript = Transcript::new(b"RangeProof");
+        RangeProof::prove_single(
+            &bp_gens,
+            &pc_gens,
+            &mut prover_transcript,
+            secret_value,
+            &blinding,
+            n,
+        )
+        .unwrap()
+    };
+
+    // Verify that the proof is valid
+    let verification_result = {
+        let mut verifier_transcript = Transcript::new(b"RangeProof");
+        proof.verify_single(&bp_gens, &pc_gens, &mut verifier_transcript, &commitment, n)
+    };
+
+    assert!(verification_result.is_ok());
+
+    // Get metrics from the proof
+    let runtime_proof_size_bytes = std::mem::size_of_val(&proof);
+    let serilized_proof_size_bytes = proof.to_bytes().len();
+    println!(
+        "Range proof ({} bits) metrics: \n\tSize runtime (bytes): {} \n\tSize serialized (bytes): {}",
+        n, runtime_proof_size_bytes, serilized_proof_size_bytes
+    );
+}
+
+#[test]
+fn test_run() {
+    run();
+}
+
+#[allow(dead_code)]
+fn benchmark(n: usize, samples: u32) {
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    let pc_gens = PedersenGens::default();
+    let bp_gens = BulletproofGens::new(n, 1);
+
+    let secret_value: u64 = if n == 64 {
+        u64::MAX / 2
+    } else {
+        (1u64 << (n - 1)) + 1
+    };
+
+    let mut total_proving = Duration::new(0, 0);
+    let mut total_verifying = Duration::new(0, 0);
+    for _ in 0..samples {
+        let blinding = Scalar::random(&mut rng);
+
+        let start = Instant::now();
+        let (proof, commitment) = {
+            let mut prover_transcript = Transcript::new(b"RangeProof");
+            RangeProof::prove_single(
+                &bp_gens,
+                &pc_gens,
+                &mut prover_transcript,
+                secret_value,
+                &blinding,
+                n,
+            )
+            .unwrap()
+        };
+        total_proving += start.elapsed();
+
+        let start = Instant::now();
+        let verification_result = {
+            let mut verifier_transcript = Transcript::new(b"RangeProof");
+            proof.verify_single(&bp_gens, &pc_gens, &mut verifier_transcript, &commitment, n)
+        };
+        total_verifying += start.elapsed();
+
+        assert!(verification_result.is_ok());
+    }
+
+    let proving_avg = total_proving / samples;
+    let proving_avg =
+        proving_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (proving_avg.as_secs() as f64);
+    println!(
+        "Range proof ({} bits) average proving time ({} samples): {:?} seconds",
+        n, samples, proving_avg
+    );
+
+    let verifying_avg = total_verifying / samples;
+    let verifying_avg =
+        verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (verifying_avg.as_secs() as f64);
+    println!(
+        "Range proof ({} bits) average verifying time ({} samples): {:?} seconds",
+        n, samples, verifying_avg
+    );
+}
+
+#[test]
+fn test_benchmark() {
+    for n in BIT_WIDTHS {
+        benchmark(n, SAMPLES);
+    }
+}
@@ -0,0 +1,186 @@
+// Aggregated multi-party range proofs.
+//
+// A single Bulletproof can prove that m committed values are each in
+// [0, 2^n) simultaneously, with proof size growing only as O(log(n*m))
+// rather than linearly in m. This follows the dealer/party MPC protocol: each
+// party commits to its bits, the dealer drives two rounds of Fiat-Shamir
+// challenges (the bit-commitment challenge and the polynomial-commitment
+// challenge) through a shared transcript, and finally assembles the combined
+// inner-product argument from the parties' proof shares. This mirrors how
+// confidential transactions batch many range proofs (e.g. several outputs in
+// one transaction) into a single aggregate proof.
+
+use super::*;
+use bulletproofs::range_proof_mpc::dealer::Dealer;
+use bulletproofs::range_proof_mpc::party::Party;
+use bulletproofs::RangeProof;
+use curve25519_dalek_ng::ristretto::CompressedRistretto;
+use rand::RngCore;
+
+/// Produces a single aggregated range proof that all of `values` lie in
+/// `[0, 2^n)`, using the dealer/party MPC protocol with one party per value.
+pub fn prove_aggregated(
+    bp_gens: &BulletproofGens,
+    pc_gens: &PedersenGens,
+    n: usize,
+    values: &[u64],
+    blindings: &[Scalar],
+) -> (RangeProof, Vec<CompressedRistretto>) {
+    let m = values.len();
+    assert_eq!(m, blindings.len());
+
+    let mut transcript = Transcript::new(b"AggregatedRangeProof");
+
+    // Round 0: each party is assigned its position in the aggregation.
+    let parties: Vec<_> = values
+        .iter()
+        .zip(blindings.iter())
+        .map(|(&v, &b)| Party::new(bp_gens, pc_gens, v, b, n).unwrap())
+        .collect();
+
+    let dealer = Dealer::new(bp_gens, pc_gens, &mut transcript, n, m).unwrap();
+
+    let (parties, bit_commitments): (Vec<_>, Vec<_>) = parties
+        .into_iter()
+        .enumerate()
+        .map(|(j, p)| p.assign_position(j).unwrap())
+        .unzip();
+
+    // Round 1: dealer broadcasts the bit-commitment (`y`, `z`) challenge.
+    let (dealer, bit_challenge) = dealer.receive_bit_commitments(bit_commitments).unwrap();
+
+    let (parties, poly_commitments): (Vec<_>, Vec<_>) = parties
+        .into_iter()
+        .map(|p| p.apply_challenge(&bit_challenge).unwrap())
+        .unzip();
+
+    // Round 2: dealer broadcasts the polynomial-commitment (`x`) challenge.
+    let (dealer, poly_challenge) = dealer.receive_poly_commitments(poly_commitments).unwrap();
+
+    let proof_shares: Vec<_> = parties
+        .into_iter()
+        .map(|p| p.apply_challenge(&poly_challenge).unwrap())
+        .collect();
+
+    // The dealer assembles the final aggregated proof from the shares.
+    let proof = dealer.receive_trusted_shares(&proof_shares).unwrap();
+
+    let commitments = parties_commitments(bp_gens, pc_gens, n, values, blindings);
+
+    (proof, commitments)
+}
+
+fn parties_commitments(
+    bp_gens: &BulletproofGens,
+    pc_gens: &PedersenGens,
+    n: usize,
+    values: &[u64],
+    blindings: &[Scalar],
+) -> Vec<CompressedRistretto> {
+    // The per-value Pedersen commitments are public; recompute them here for
+    // the benchmark (a real deployment would carry them alongside the proof).
+    let _ = (bp_gens, n);
+    values
+        .iter()
+        .zip(blindings.iter())
+        .map(|(&v, &b)| pc_gens.commit(Scalar::from(v), b).compress())
+        .collect()
+}
+
+pub fn verify_aggregated(
+    bp_gens: &BulletproofGens,
+    pc_gens: &PedersenGens,
+    proof: &RangeProof,
+    commitments: &[CompressedRistretto],
+    n: usize,
+) -> Result<(), bulletproofs::ProofError> {
+    let mut transcript = Transcript::new(b"AggregatedRangeProof");
+    proof.verify_multiple(bp_gens, pc_gens, &mut transcript, commitments, n)
+}
+
+const AGGREGATION_SIZES: [usize; 5] = [1, 2, 4, 8, 16];
+
+pub fn run() {
+    let n = 32;
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    for &m in AGGREGATION_SIZES.iter() {
+        // GENS_CAPACITY must cover n*m for the aggregated proof.
+        let bp_gens = BulletproofGens::new(n, m);
+        let pc_gens = PedersenGens::default();
+
+        let values: Vec<u64> = (0..m).map(|_| rng.next_u32() as u64).collect();
+        let blindings: Vec<Scalar> = (0..m).map(|_| Scalar::random(&mut rng)).collect();
+
+        let (proof, commitments) = prove_aggregated(&bp_gens, &pc_gens, n, &values, &blindings);
+
+        let verification_result = verify_aggregated(&bp_gens, &pc_gens, &proof, &commitments, n);
+        assert!(verification_result.is_ok());
+
+        let serialized_proof_size_bytes = proof.to_bytes().len();
+        println!(
+            "Aggregated range proof ({} values x {} bits) metrics: \n\tSize serialized (bytes): {} \n\tBytes per value: {:.2}",
+            m, n, serialized_proof_size_bytes, (serialized_proof_size_bytes as f64) / (m as f64)
+        );
+    }
+}
+
+#[test]
+fn test_run() {
+    run();
+}
+
+#[allow(dead_code)]
+fn benchmark(samples: u32) {
+    let n = 32;
+    let mut rng: StdRng = SeedableRng::from_seed(RANDOMNESS_SEED);
+
+    for &m in AGGREGATION_SIZES.iter() {
+        let bp_gens = BulletproofGens::new(n, m);
+        let pc_gens = PedersenGens::default();
+
+        let values: Vec<u64> = (0..m).map(|_| rng.next_u32() as u64).collect();
+        let blindings: Vec<Scalar> = (0..m).map(|_| Scalar::random(&mut rng)).collect();
+
+        let mut total_proving = Duration::new(0, 0);
+        let mut total_verifying = Duration::new(0, 0);
+        for _ in 0..samples {
+            let start = Instant::now();
+            let (proof, commitments) =
+                prove_aggregated(&bp_gens, &pc_gens, n, &values, &blindings);
+            total_proving += start.elapsed();
+
+            let start = Instant::now();
+            let verification_result =
+                verify_aggregated(&bp_gens, &pc_gens, &proof, &commitments, n);
+            total_verifying += start.elapsed();
+
+            assert!(verification_result.is_ok());
+        }
+
+        let proving_avg = total_proving / samples;
+        let proving_avg =
+            proving_avg.subsec_nanos() as f64 / 1_000_000_000f64 + (proving_avg.as_secs() as f64);
+        println!(
+            "Aggregation size {}: average proving time {:?} seconds ({:?} per value)",
+            m,
+            proving_avg,
+            proving_avg / (m as f64)
+        );
+
+        let verifying_avg = total_verifying / samples;
+        let verifying_avg = verifying_avg.subsec_nanos() as f64 / 1_000_000_000f64
+            + (verifying_avg.as_secs() as f64);
+        println!(
+            "Aggregation size {}: average verifying time {:?} seconds ({:?} per value)",
+            m,
+            verifying_avg,
+            verifying_avg / (m as f64)
+        );
+    }
+}
+
+#[test]
+fn test_benchmark() {
+    benchmark(SAMPLES);
+}
@@ -0,0 +1,43 @@
+// Parameter-sweep driver: runs the STARK and Bulletproof MiMC backends
+// across a grid of `BenchConfig`s and collects the results as CSV, turning
+// the crate's fixed benchmarks into a reusable harness for producing
+// cross-system comparison tables.
+
+use crate::bench_config::{BenchConfig, BenchRow};
+use crate::hash::mimc::{bulletproof, stark};
+
+/// Runs every backend once per config in `configs`, in backend-major order
+/// (all STARK rows followed by all Bulletproof rows).
+pub fn run(configs: &[BenchConfig]) -> Vec<BenchRow> {
+    let mut rows = Vec::with_capacity(configs.len() * 2);
+    for config in configs {
+        rows.push(stark::bench_row(config));
+    }
+    for config in configs {
+        rows.push(bulletproof::bench_row(config));
+    }
+    rows
+}
+
+/// Renders sweep rows as CSV text: a header line followed by one line per
+/// row.
+pub fn to_csv(rows: &[BenchRow]) -> String {
+    let mut csv = String::from(BenchRow::csv_header());
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(&row.to_csv_row());
+        csv.push('\n');
+    }
+    csv
+}
+
+#[test]
+fn test_sweep_csv() {
+    let configs = vec![BenchConfig::new(7, 2), BenchConfig::new(15, 2)];
+    let rows = run(&configs);
+    assert_eq!(rows.len(), configs.len() * 2);
+
+    let csv = to_csv(&rows);
+    assert!(csv.starts_with(BenchRow::csv_header()));
+    assert_eq!(csv.lines().count(), rows.len() + 1);
+}